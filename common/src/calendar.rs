@@ -1,88 +1,226 @@
-pub mod moon {
-    use crate::rtclock;
-
-    // 2.551442882×10^6 seconds
-    const AVG_SYNODIC_MONTH_SECS: u64 = 2_551_443;
-
-    // https://aa.usno.navy.mil/calculated/moon/fraction?year=2025&task=00&tz=0.00&tz_sign=-1&tz_label=false&submit=Get+Data
-    const REFERENCE_PHASE: f64 = 0.0;
-    const REFERENCE_TIMESTAMP: rtclock::InstantSecs = rtclock::InstantSecs::from_ticks(1763596800); // Nov 20, 2025 midnight UTC
-
-    /// Gets the phase of the moon as a float [0, 1), representing the fraction of
-    /// the way through the current lunar cycle from new to full to new again. Note
-    /// that this is *not* the same as the fractional illumination.
-    pub fn get_phase(instant: rtclock::InstantSecs) -> f64 {
-        let second_since_reference = (instant - REFERENCE_TIMESTAMP).to_secs();
-        let remainder = second_since_reference % AVG_SYNODIC_MONTH_SECS;
-        let phase_offset = (remainder as f64) / (AVG_SYNODIC_MONTH_SECS as f64);
-        let mut phase = REFERENCE_PHASE + phase_offset;
-        if phase >= 1.0 {
-            phase -= 1.0;
+pub mod eclipse;
+pub mod meteors;
+pub mod moon;
+pub mod planets;
+pub mod search;
+pub mod seasons;
+pub mod sun;
+pub mod twilight;
+
+use crate::{rtclock, timescale};
+
+/// Unix timestamp of the J2000.0 epoch (Jan 1, 2000 12:00 UTC), which the
+/// astronomical series in this module are expressed relative to.
+const J2000_TIMESTAMP: i64 = 946_728_000;
+
+const J2000_JULIAN_DAY: f64 = 2_451_545.0;
+
+const SECS_PER_DAY: f64 = 86_400.0;
+
+/// Equatorial radius of the earth in kilometres.
+pub(crate) const EARTH_RADIUS_KM: f64 = 6378.14;
+
+/// One astronomical unit in kilometres.
+pub(crate) const AU_KM: f64 = 149_597_870.7;
+
+/// Rate at which the hour angle of a fixed point on the celestial sphere
+/// increases, in degrees per day. Bodies other than the moon move slowly
+/// enough against the stars that this is a good first approximation.
+pub(crate) const HOUR_ANGLE_RATE: f64 = 360.985_647;
+
+/// A location on the earth's surface from which events are observed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Observer {
+    /// Geodetic latitude in degrees, positive north.
+    pub latitude: f64,
+    /// Longitude in degrees, positive east.
+    pub longitude: f64,
+    /// Height above sea level in meters.
+    pub elevation: f64,
+}
+
+/// Position of a body on the celestial sphere, relative to the equator and
+/// equinox of date.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EquatorialPosition {
+    /// Right ascension in degrees [0, 360).
+    pub right_ascension: f64,
+    /// Declination in degrees [-90, 90].
+    pub declination: f64,
+}
+
+impl EquatorialPosition {
+    /// Converts to the local horizon coordinates of an observer at `days`
+    /// since J2000.0. Neither refraction nor parallax are applied.
+    pub(crate) fn to_horizontal(self, days: f64, observer: &Observer) -> HorizontalPosition {
+        let hour_angle = local_hour_angle(&self, days, observer);
+        let altitude = asin_deg(
+            sin_deg(observer.latitude) * sin_deg(self.declination)
+                + cos_deg(observer.latitude) * cos_deg(self.declination) * cos_deg(hour_angle),
+        );
+        // Measured westwards from south, so turn around to measure from north
+        let azimuth = atan2_deg(
+            sin_deg(hour_angle),
+            cos_deg(hour_angle) * sin_deg(observer.latitude)
+                - libm::tan(self.declination.to_radians()) * cos_deg(observer.latitude),
+        ) + 180.0;
+        HorizontalPosition {
+            altitude,
+            azimuth: normalize_degrees(azimuth),
         }
-        phase
     }
+}
 
-    pub fn get_phase_label(phase: f64) -> &'static str {
-        match phase {
-            _ if phase <= 1.0 / 16.0 => "new moon",
-            _ if phase <= 3.0 / 16.0 => "waxing crescent",
-            _ if phase <= 5.0 / 16.0 => "first quarter",
-            _ if phase <= 7.0 / 16.0 => "waxing gibbous",
-            _ if phase <= 9.0 / 16.0 => "full moon",
-            _ if phase <= 11.0 / 16.0 => "waning gibbous",
-            _ if phase <= 13.0 / 16.0 => "last quarter",
-            _ if phase <= 15.0 / 16.0 => "waning crescent",
-            _ => "new moon",
+/// Position of a body in an observer's sky.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HorizontalPosition {
+    /// Altitude above the horizon in degrees [-90, 90].
+    pub altitude: f64,
+    /// Azimuth in degrees [0, 360), measured eastwards from north.
+    pub azimuth: f64,
+}
+
+/// Position of a body relative to the ecliptic and equinox of date, as seen
+/// from the centre of the earth.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EclipticPosition {
+    /// Apparent ecliptic longitude in degrees [0, 360).
+    pub longitude: f64,
+    /// Ecliptic latitude in degrees [-90, 90].
+    pub latitude: f64,
+    /// Distance from the centre of the earth in kilometres.
+    pub distance: f64,
+}
+
+impl EclipticPosition {
+    /// Converts to equatorial coordinates using the obliquity at `days` since
+    /// J2000.0.
+    pub(crate) fn to_equatorial(self, days: f64) -> EquatorialPosition {
+        let obliquity = obliquity(days);
+        let sin_longitude = sin_deg(self.longitude);
+        EquatorialPosition {
+            right_ascension: normalize_degrees(atan2_deg(
+                sin_longitude * cos_deg(obliquity)
+                    - libm::tan(self.latitude.to_radians()) * sin_deg(obliquity),
+                cos_deg(self.longitude),
+            )),
+            declination: asin_deg(
+                sin_deg(self.latitude) * cos_deg(obliquity)
+                    + cos_deg(self.latitude) * sin_deg(obliquity) * sin_longitude,
+            ),
         }
     }
+}
 
-    #[rustfmt::skip]
-    const ILLUMINATION_TABLE: [f64; 31] = [
-        0.0,
-        0.0032,
-        0.0213,
-        0.0565,
-        0.1075,
-        0.1725,
-        0.2499,
-        0.3375,
-        0.4331,
-        0.5338,
-        0.6362,
-        0.7361,
-        0.8280,
-        0.9058,
-        0.9631,
-        0.9942,
-        0.9954,
-        0.9662,
-        0.9093,
-        0.8302,
-        0.7354,
-        0.6318,
-        0.5254,
-        0.4212,
-        0.3233,
-        0.2347,
-        0.1578,
-        0.0948,
-        0.0472,
-        0.0161,
-        0.0024,
-    ];
-
-    /// Calculates the illumination percent from the moon's phase using a lookup
-    /// table.
-    pub fn get_illumination(phase: f64) -> f64 {
-        let fractional_index = phase * ILLUMINATION_TABLE.len() as f64;
-
-        let low_index = fractional_index as usize % ILLUMINATION_TABLE.len();
-        let high_index = (low_index + 1) % ILLUMINATION_TABLE.len();
-
-        let low_val = ILLUMINATION_TABLE[low_index];
-        let high_val = ILLUMINATION_TABLE[high_index];
-        let a = fractional_index - (low_index as f64);
-
-        low_val * (1.0 - a) + high_val * a
-    }
+/// Converts a timestamp to (fractional) days since J2000.0.
+pub(crate) fn days_since_j2000(timestamp: rtclock::Timestamp) -> f64 {
+    (timestamp.secs() - J2000_TIMESTAMP) as f64 / SECS_PER_DAY
+}
+
+/// Converts (fractional) days since J2000.0 back to a timestamp, rounded to
+/// the nearest second.
+pub(crate) fn timestamp_from_days_since_j2000(days: f64) -> rtclock::Timestamp {
+    rtclock::Timestamp::from_secs(libm::round(days * SECS_PER_DAY) as i64 + J2000_TIMESTAMP)
+}
+
+/// Finds local mean midnight at the start of the day containing `timestamp`,
+/// in days since J2000.0. This defines the observer's day independently of
+/// any time zone.
+pub(crate) fn local_midnight_days(timestamp: rtclock::Timestamp, observer: &Observer) -> f64 {
+    // J2000 falls on noon, so local mean midnight is half a day earlier
+    let local_days = days_since_j2000(timestamp) + 0.5 + observer.longitude / 360.0;
+    libm::floor(local_days) - 0.5 - observer.longitude / 360.0
+}
+
+/// Depression of the apparent horizon below the astronomical horizon, in
+/// degrees, for an observer at elevation.
+pub(crate) fn horizon_dip(observer: &Observer) -> f64 {
+    // 1.76' * sqrt(elevation in meters), Bennett's approximation
+    1.76 / 60.0 * libm::sqrt(observer.elevation.max(0.0))
+}
+
+/// Converts days since J2000.0 in universal time to terrestrial time, which
+/// the sun and moon's series are expressed in.
+pub(crate) fn ephemeris_days(days: f64) -> f64 {
+    days + timescale::tt_minus_utc_at(days) / SECS_PER_DAY
+}
+
+/// Converts a Julian Ephemeris Day (in terrestrial time) to days since
+/// J2000.0 in universal time.
+pub(crate) fn days_from_julian_ephemeris_day(jde: f64) -> f64 {
+    let days = jde - J2000_JULIAN_DAY;
+    days - timescale::tt_minus_utc_at(days) / SECS_PER_DAY
+}
+
+/// Longitude of the moon's ascending node in degrees, which drives the
+/// dominant terms of nutation.
+fn lunar_node_longitude(days: f64) -> f64 {
+    125.04 - 1934.136 * days / 36525.0
+}
+
+/// Nutation in longitude in degrees, to be added to geometric longitudes to
+/// get apparent ones. Only the dominant term is included (accurate to ~0.5").
+pub(crate) fn nutation_in_longitude(days: f64) -> f64 {
+    -0.00478 * sin_deg(lunar_node_longitude(days))
+}
+
+/// True obliquity of the ecliptic in degrees.
+pub(crate) fn obliquity(days: f64) -> f64 {
+    let t = days / 36525.0;
+    23.439291111 - 0.0130041667 * t + 0.00256 * cos_deg(lunar_node_longitude(days))
+}
+
+/// Local hour angle of a body in degrees [-180, 180), i.e. how far west of the
+/// observer's meridian it is.
+pub(crate) fn local_hour_angle(
+    position: &EquatorialPosition,
+    days: f64,
+    observer: &Observer,
+) -> f64 {
+    normalize_degrees_signed(
+        timescale::apparent_sidereal_degrees(days) + observer.longitude - position.right_ascension,
+    )
+}
+
+/// Cosine of the hour angle at which a body at `position` crosses `altitude`
+/// (in degrees). Outside [-1, 1] the body stays below (> 1) or above (< -1)
+/// that altitude all day.
+pub(crate) fn cos_crossing_hour_angle(
+    position: &EquatorialPosition,
+    observer: &Observer,
+    altitude: f64,
+) -> f64 {
+    (sin_deg(altitude) - sin_deg(observer.latitude) * sin_deg(position.declination))
+        / (cos_deg(observer.latitude) * cos_deg(position.declination))
+}
+
+/// Wraps an angle in degrees into [0, 360).
+pub(crate) fn normalize_degrees(degrees: f64) -> f64 {
+    let wrapped = degrees - 360.0 * libm::floor(degrees / 360.0);
+    if wrapped >= 360.0 { 0.0 } else { wrapped }
+}
+
+/// Wraps an angle in degrees into [-180, 180).
+pub(crate) fn normalize_degrees_signed(degrees: f64) -> f64 {
+    normalize_degrees(degrees + 180.0) - 180.0
+}
+
+pub(crate) fn sin_deg(degrees: f64) -> f64 {
+    libm::sin(degrees.to_radians())
+}
+
+pub(crate) fn cos_deg(degrees: f64) -> f64 {
+    libm::cos(degrees.to_radians())
+}
+
+pub(crate) fn asin_deg(x: f64) -> f64 {
+    libm::asin(x).to_degrees()
+}
+
+pub(crate) fn acos_deg(x: f64) -> f64 {
+    libm::acos(x).to_degrees()
+}
+
+pub(crate) fn atan2_deg(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x).to_degrees()
 }
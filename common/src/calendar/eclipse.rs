@@ -0,0 +1,427 @@
+use crate::calendar::moon::{self, LUNATION_EPOCH_DAYS, MOON_RADIUS_KM, SYNODIC_MONTH_DAYS};
+use crate::calendar::search::{Direction, Search};
+use crate::calendar::twilight::Interval;
+use crate::calendar::{
+    AU_KM, EquatorialPosition, Observer, asin_deg, cos_deg, days_from_julian_ephemeris_day,
+    days_since_j2000, horizon_dip, sin_deg, sun, timestamp_from_days_since_j2000,
+};
+use crate::rtclock;
+
+/// Beyond this |sin F|, the moon is too far from a node for an eclipse.
+const NODE_LIMIT: f64 = 0.36;
+
+/// The sun's semi-diameter at 1 AU, in degrees.
+const SUN_SEMI_DIAMETER: f64 = 959.63 / 3600.0;
+
+/// Half-width of the window searched for local contacts either side of the
+/// greatest eclipse, in seconds (0.2 days). The partial phase lasts at most a
+/// couple of hours at any one place, and the local maximum can be a couple of
+/// hours either side of the greatest eclipse.
+const LOCAL_SEARCH_SECS: i64 = 17_280;
+
+/// How the local window is searched for contacts, sampling every six minutes.
+const LOCAL_SEARCH: Search = Search {
+    step: 360,
+    tolerance: 1,
+};
+
+/// How deep an eclipse gets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EclipseKind {
+    /// The moon only passes through the earth's penumbra, which dims it
+    /// slightly. Lunar eclipses only.
+    Penumbral,
+    /// Part of the sun or moon is covered, but never all of it.
+    Partial,
+    /// The moon is too small to cover the sun, leaving a ring. Solar eclipses
+    /// only.
+    Annular,
+    /// Total along part of the eclipse's path and annular along the rest.
+    /// Solar eclipses only.
+    Hybrid,
+    Total,
+}
+
+impl EclipseKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            EclipseKind::Penumbral => "penumbral",
+            EclipseKind::Partial => "partial",
+            EclipseKind::Annular => "annular",
+            EclipseKind::Hybrid => "hybrid",
+            EclipseKind::Total => "total",
+        }
+    }
+}
+
+/// A solar eclipse, as seen from the earth as a whole.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolarEclipse {
+    pub kind: EclipseKind,
+    /// When the axis of the moon's shadow passes closest to the centre of the
+    /// earth.
+    pub maximum: rtclock::Timestamp,
+    /// Least distance from the axis of the moon's shadow to the centre of the
+    /// earth, in equatorial radii. Positive when the axis passes north of the
+    /// centre.
+    pub gamma: f64,
+}
+
+/// A lunar eclipse. These look the same from anywhere the moon is up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LunarEclipse {
+    pub kind: EclipseKind,
+    pub maximum: rtclock::Timestamp,
+    /// Fraction of the moon's diameter inside the penumbra at maximum.
+    pub penumbral_magnitude: f64,
+    /// Fraction of the moon's diameter inside the umbra at maximum. Negative
+    /// for penumbral eclipses.
+    pub umbral_magnitude: f64,
+    /// When the moon is at least partly inside the penumbra.
+    pub penumbral: Interval,
+    /// When the moon is at least partly inside the umbra.
+    pub partial: Option<Interval>,
+    /// When the moon is entirely inside the umbra.
+    pub total: Option<Interval>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Eclipse {
+    Solar(SolarEclipse),
+    Lunar(LunarEclipse),
+}
+
+impl Eclipse {
+    pub fn kind(&self) -> EclipseKind {
+        match self {
+            Eclipse::Solar(eclipse) => eclipse.kind,
+            Eclipse::Lunar(eclipse) => eclipse.kind,
+        }
+    }
+
+    pub fn maximum(&self) -> rtclock::Timestamp {
+        match self {
+            Eclipse::Solar(eclipse) => eclipse.maximum,
+            Eclipse::Lunar(eclipse) => eclipse.maximum,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Eclipse::Solar(_) => "solar eclipse",
+            Eclipse::Lunar(_) => "lunar eclipse",
+        }
+    }
+}
+
+/// A solar eclipse as seen by a particular observer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalCircumstances {
+    /// When the moon's limb first touches the sun's. This and `last_contact`
+    /// may be while the sun is below the horizon, if it rises or sets during
+    /// the eclipse.
+    pub first_contact: rtclock::Timestamp,
+    /// When the centres of the moon and sun are closest.
+    pub maximum: rtclock::Timestamp,
+    /// When the moon's limb leaves the sun's.
+    pub last_contact: rtclock::Timestamp,
+    /// Fraction of the sun's diameter covered by the moon at maximum. This is
+    /// at least 1 for a total eclipse.
+    pub magnitude: f64,
+    /// Fraction of the sun's disk area covered by the moon at maximum.
+    pub obscuration: f64,
+}
+
+/// Iterator over successive eclipses, returned by `get_next_eclipses`.
+pub struct NextEclipses {
+    after: rtclock::Timestamp,
+    /// Number of the next new or full moon to check, counting half lunations
+    /// from the new moon of Jan 6, 2000.
+    half_lunation: i64,
+}
+
+impl Iterator for NextEclipses {
+    type Item = Eclipse;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let eclipse = eclipse_at(self.half_lunation);
+            self.half_lunation += 1;
+            if let Some(eclipse) = eclipse.filter(|eclipse| eclipse.maximum() > self.after) {
+                return Some(eclipse);
+            }
+        }
+    }
+}
+
+/// Gets the solar and lunar eclipses occurring after `instant`, in order.
+/// There are between four and seven a year, so the iterator is unbounded.
+///
+/// Instants of greatest eclipse are accurate to a few minutes. See Meeus,
+/// "Astronomical Algorithms", ch. 54.
+pub fn get_next_eclipses(instant: impl Into<rtclock::Timestamp>) -> NextEclipses {
+    let instant = instant.into();
+    let lunations = (days_since_j2000(instant) - LUNATION_EPOCH_DAYS) / SYNODIC_MONTH_DAYS;
+    NextEclipses {
+        after: instant,
+        // Start a half lunation early, as the true syzygy can precede the
+        // mean one
+        half_lunation: libm::floor(lunations * 2.0) as i64 - 1,
+    }
+}
+
+/// Works out how a solar eclipse looks to an observer, from the topocentric
+/// positions of the sun and moon. Returns `None` if it isn't visible here,
+/// either because the moon misses the sun or because the sun is below the
+/// horizon throughout.
+pub fn get_local_circumstances(
+    eclipse: &SolarEclipse,
+    observer: &Observer,
+) -> Option<LocalCircumstances> {
+    let start = eclipse.maximum - LOCAL_SEARCH_SECS;
+    let end = eclipse.maximum + LOCAL_SEARCH_SECS;
+
+    // Separation of the limbs, which is negative while they overlap
+    let overlap = |instant: rtclock::Timestamp| {
+        let disks = disks_at(days_since_j2000(instant), observer);
+        disks.separation - disks.sun_radius - disks.moon_radius
+    };
+
+    let mut contacts = LOCAL_SEARCH.crossings(overlap, 0.0, start, Some(end));
+    let first_contact = contacts
+        .find(|crossing| crossing.direction == Direction::Falling)?
+        .instant;
+    let last_contact = contacts
+        .find(|crossing| crossing.direction == Direction::Rising)?
+        .instant;
+    let maximum = LOCAL_SEARCH.refine_minimum(&overlap, first_contact, last_contact);
+
+    // Visible if the sun's upper limb is above the horizon at some point
+    let horizon = sun::SUNRISE_ALTITUDE - horizon_dip(observer);
+    let is_visible = (0..)
+        .map(|step| first_contact + step * LOCAL_SEARCH.step)
+        .take_while(|instant| *instant < last_contact)
+        .chain([maximum, last_contact])
+        .any(|instant| {
+            let days = days_since_j2000(instant);
+            let position = sun::ecliptic_position_at(days).to_equatorial(days);
+            position.to_horizontal(days, observer).altitude >= horizon
+        });
+    if !is_visible {
+        return None;
+    }
+
+    let disks = disks_at(days_since_j2000(maximum), observer);
+    Some(LocalCircumstances {
+        first_contact,
+        maximum,
+        last_contact,
+        magnitude: (disks.sun_radius + disks.moon_radius - disks.separation)
+            / (2.0 * disks.sun_radius),
+        obscuration: disks.obscuration(),
+    })
+}
+
+/// Apparent sizes and separation of the sun and moon for an observer, in
+/// degrees.
+struct Disks {
+    sun_radius: f64,
+    moon_radius: f64,
+    separation: f64,
+}
+
+impl Disks {
+    /// Fraction of the sun's area covered by the moon, from the area of
+    /// intersection of the two circles.
+    fn obscuration(&self) -> f64 {
+        let (sun, moon, distance) = (self.sun_radius, self.moon_radius, self.separation);
+        if distance >= sun + moon {
+            return 0.0;
+        }
+        if distance <= libm::fabs(moon - sun) {
+            return if moon >= sun {
+                1.0
+            } else {
+                (moon * moon) / (sun * sun)
+            };
+        }
+
+        let moon_angle =
+            libm::acos((distance * distance + moon * moon - sun * sun) / (2.0 * distance * moon));
+        let sun_angle =
+            libm::acos((distance * distance + sun * sun - moon * moon) / (2.0 * distance * sun));
+        let kite = libm::sqrt(
+            (-distance + moon + sun)
+                * (distance + moon - sun)
+                * (distance - moon + sun)
+                * (distance + moon + sun),
+        );
+        let area = moon * moon * moon_angle + sun * sun * sun_angle - kite / 2.0;
+        area / (core::f64::consts::PI * sun * sun)
+    }
+}
+
+fn disks_at(days: f64, observer: &Observer) -> Disks {
+    let sun_ecliptic = sun::ecliptic_position_at(days);
+    let sun_position = sun_ecliptic.to_equatorial(days);
+    let (moon_position, moon_distance) = moon::topocentric_position_at(days, observer);
+    Disks {
+        sun_radius: SUN_SEMI_DIAMETER * AU_KM / sun_ecliptic.distance,
+        moon_radius: asin_deg(MOON_RADIUS_KM / moon_distance),
+        separation: angular_separation(&sun_position, &moon_position),
+    }
+}
+
+/// Angle between two positions in degrees, using the haversine formula, which
+/// stays accurate at small separations.
+fn angular_separation(a: &EquatorialPosition, b: &EquatorialPosition) -> f64 {
+    let half_declination = sin_deg((b.declination - a.declination) / 2.0);
+    let half_right_ascension = sin_deg((b.right_ascension - a.right_ascension) / 2.0);
+    let haversine = half_declination * half_declination
+        + cos_deg(a.declination)
+            * cos_deg(b.declination)
+            * half_right_ascension
+            * half_right_ascension;
+    2.0 * asin_deg(libm::sqrt(haversine.clamp(0.0, 1.0)))
+}
+
+/// Checks for an eclipse at the new or full moon numbered `half_lunation`
+/// (new moons are even), following Meeus ch. 54.
+fn eclipse_at(half_lunation: i64) -> Option<Eclipse> {
+    let k = half_lunation as f64 / 2.0;
+    let is_solar = half_lunation.rem_euclid(2) == 0;
+    let t = k / 1236.85;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let latitude_argument =
+        160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4;
+    if libm::fabs(sin_deg(latitude_argument)) > NODE_LIMIT {
+        return None;
+    }
+
+    let mean_jde = 2451550.09766 + SYNODIC_MONTH_DAYS * k + 0.00015437 * t2 - 0.000000150 * t3
+        + 0.00000000073 * t4;
+    let sun_anomaly = 2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3;
+    let moon_anomaly =
+        201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3 - 0.000000058 * t4;
+    let node = 124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3;
+    let eccentricity = 1.0 - 0.002516 * t - 0.0000074 * t2;
+    let f1 = latitude_argument - 0.02665 * sin_deg(node);
+    let a1 = 299.77 + 0.107408 * k - 0.009173 * t2;
+    let (m, mp, e) = (sun_anomaly, moon_anomaly, eccentricity);
+
+    let correction = if is_solar {
+        -0.4075 * sin_deg(mp) + 0.1721 * e * sin_deg(m)
+    } else {
+        -0.4065 * sin_deg(mp) + 0.1727 * e * sin_deg(m)
+    } + 0.0161 * sin_deg(2.0 * mp)
+        - 0.0097 * sin_deg(2.0 * f1)
+        + 0.0073 * e * sin_deg(mp - m)
+        - 0.0050 * e * sin_deg(mp + m)
+        - 0.0023 * sin_deg(mp - 2.0 * f1)
+        + 0.0021 * e * sin_deg(2.0 * m)
+        + 0.0012 * sin_deg(mp + 2.0 * f1)
+        + 0.0006 * e * sin_deg(2.0 * mp + m)
+        - 0.0004 * sin_deg(3.0 * mp)
+        - 0.0003 * e * sin_deg(m + 2.0 * f1)
+        + 0.0003 * sin_deg(a1)
+        - 0.0002 * e * sin_deg(m - 2.0 * f1)
+        - 0.0002 * e * sin_deg(2.0 * mp - m)
+        - 0.0002 * sin_deg(node);
+    let maximum_days = days_from_julian_ephemeris_day(mean_jde + correction);
+
+    let p = 0.2070 * e * sin_deg(m) + 0.0024 * e * sin_deg(2.0 * m) - 0.0392 * sin_deg(mp)
+        + 0.0116 * sin_deg(2.0 * mp)
+        - 0.0073 * e * sin_deg(mp + m)
+        + 0.0067 * e * sin_deg(mp - m)
+        + 0.0118 * sin_deg(2.0 * f1);
+    let q = 5.2207 - 0.0048 * e * cos_deg(m) + 0.0020 * e * cos_deg(2.0 * m)
+        - 0.3299 * cos_deg(mp)
+        - 0.0060 * e * cos_deg(mp + m)
+        + 0.0041 * e * cos_deg(mp - m);
+    let w = libm::fabs(cos_deg(f1));
+    let gamma = (p * cos_deg(f1) + q * sin_deg(f1)) * (1.0 - 0.0048 * w);
+    // Radius of the umbral cone in the fundamental plane, in earth radii
+    let u = 0.0059 + 0.0046 * e * cos_deg(m) - 0.0182 * cos_deg(mp) + 0.0004 * cos_deg(2.0 * mp)
+        - 0.0005 * cos_deg(m + mp);
+    let abs_gamma = libm::fabs(gamma);
+
+    if is_solar {
+        solar_eclipse(maximum_days, gamma, u).map(Eclipse::Solar)
+    } else {
+        let penumbral_magnitude = (1.5573 + u - abs_gamma) / 0.5450;
+        if penumbral_magnitude <= 0.0 {
+            return None;
+        }
+        let umbral_magnitude = (1.0128 - u - abs_gamma) / 0.5450;
+
+        // Semi-durations of each phase, from the radii of the penumbra,
+        // umbra, and umbra less the moon's diameter
+        let hourly_motion = 0.5458 + 0.0400 * cos_deg(mp);
+        let phase = |radius: f64| {
+            let squared = radius * radius - gamma * gamma;
+            (squared > 0.0).then(|| {
+                let half = libm::sqrt(squared) / hourly_motion / 24.0;
+                Interval {
+                    start: timestamp_from_days_since_j2000(maximum_days - half),
+                    end: timestamp_from_days_since_j2000(maximum_days + half),
+                }
+            })
+        };
+        let partial = phase(1.0128 - u);
+        let total = phase(0.4678 - u);
+
+        Some(Eclipse::Lunar(LunarEclipse {
+            kind: if total.is_some() {
+                EclipseKind::Total
+            } else if partial.is_some() {
+                EclipseKind::Partial
+            } else {
+                EclipseKind::Penumbral
+            },
+            maximum: timestamp_from_days_since_j2000(maximum_days),
+            penumbral_magnitude,
+            umbral_magnitude,
+            penumbral: phase(1.5573 + u)?,
+            partial,
+            total,
+        }))
+    }
+}
+
+/// Classifies a solar eclipse from how close the shadow's axis passes to the
+/// earth's centre (`gamma`) and the radius of the umbral cone (`u`).
+fn solar_eclipse(maximum_days: f64, gamma: f64, u: f64) -> Option<SolarEclipse> {
+    let abs_gamma = libm::fabs(gamma);
+    if abs_gamma > 1.5433 + u {
+        return None;
+    }
+
+    let kind = if abs_gamma < 0.9972 {
+        // Central: the axis of the shadow touches the earth
+        if u < 0.0 {
+            EclipseKind::Total
+        } else if u > 0.0047 || u >= 0.00464 * libm::sqrt(1.0 - gamma * gamma) {
+            EclipseKind::Annular
+        } else {
+            EclipseKind::Hybrid
+        }
+    } else if abs_gamma < 0.9972 + libm::fabs(u) {
+        // Non-central: only the edge of the umbra or antumbra touches
+        if u < 0.0 {
+            EclipseKind::Total
+        } else {
+            EclipseKind::Annular
+        }
+    } else {
+        EclipseKind::Partial
+    };
+
+    Some(SolarEclipse {
+        kind,
+        maximum: timestamp_from_days_since_j2000(maximum_days),
+        gamma,
+    })
+}
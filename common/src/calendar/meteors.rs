@@ -0,0 +1,312 @@
+use crate::calendar::seasons::solar_longitude_days;
+use crate::calendar::twilight::{self, Interval};
+use crate::calendar::{
+    EquatorialPosition, Observer, days_since_j2000, local_midnight_days, moon, normalize_degrees,
+    normalize_degrees_signed, sun, timestamp_from_days_since_j2000,
+};
+use crate::rtclock;
+
+/// The sun's mean motion along the ecliptic, in degrees per day.
+const SOLAR_LONGITUDE_RATE: f64 = 0.985_647;
+
+/// Interval at which the sky is sampled through the night of a shower's
+/// peak, in days (15 minutes).
+const NIGHT_STEP: f64 = 15.0 / 1440.0;
+
+/// Least score (see `ViewingConditions::score`) for each rating. A dark sky
+/// is excellent with the radiant above about 45°, good above about 25° and
+/// fair above about 12°, and moonlight lowers these further.
+const EXCELLENT_SCORE: f64 = 0.7;
+const GOOD_SCORE: f64 = 0.4;
+const FAIR_SCORE: f64 = 0.2;
+
+/// An annual meteor shower, which happens as the earth passes through the
+/// trail of debris left by a comet or asteroid. Since the earth reaches the
+/// same point in its orbit at the same solar longitude each year, activity is
+/// given in terms of that rather than dates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Shower {
+    pub name: &'static str,
+    /// The point on the sky that the meteors appear to come from, for the
+    /// equinox of J2000.0. This drifts a little over the shower, but not
+    /// enough to matter for planning.
+    pub radiant: EquatorialPosition,
+    /// The sun's apparent longitude in degrees when the shower starts.
+    pub start_longitude: f64,
+    /// The sun's apparent longitude when activity peaks.
+    pub peak_longitude: f64,
+    /// The sun's apparent longitude when the shower ends.
+    pub end_longitude: f64,
+    /// Zenithal hourly rate at the peak: the number of meteors an observer
+    /// would see in an hour under a perfectly dark sky with the radiant
+    /// overhead. Real rates are usually well below this.
+    pub zenithal_hourly_rate: u16,
+}
+
+/// The major annual meteor showers, in order through the year. Figures are
+/// from the International Meteor Organization's shower calendar.
+pub static SHOWERS: [Shower; 9] = [
+    Shower {
+        name: "Quadrantids",
+        radiant: EquatorialPosition {
+            right_ascension: 230.0,
+            declination: 49.0,
+        },
+        start_longitude: 275.0,
+        peak_longitude: 283.15,
+        end_longitude: 296.0,
+        zenithal_hourly_rate: 80,
+    },
+    Shower {
+        name: "Lyrids",
+        radiant: EquatorialPosition {
+            right_ascension: 271.0,
+            declination: 34.0,
+        },
+        start_longitude: 24.0,
+        peak_longitude: 32.32,
+        end_longitude: 40.0,
+        zenithal_hourly_rate: 18,
+    },
+    Shower {
+        name: "Eta Aquariids",
+        radiant: EquatorialPosition {
+            right_ascension: 338.0,
+            declination: -1.0,
+        },
+        start_longitude: 29.0,
+        peak_longitude: 45.5,
+        end_longitude: 67.0,
+        zenithal_hourly_rate: 50,
+    },
+    Shower {
+        name: "S. Delta Aquariids",
+        radiant: EquatorialPosition {
+            right_ascension: 340.0,
+            declination: -16.0,
+        },
+        start_longitude: 110.0,
+        peak_longitude: 127.0,
+        end_longitude: 150.0,
+        zenithal_hourly_rate: 25,
+    },
+    Shower {
+        name: "Perseids",
+        radiant: EquatorialPosition {
+            right_ascension: 48.0,
+            declination: 58.0,
+        },
+        start_longitude: 115.0,
+        peak_longitude: 140.0,
+        end_longitude: 151.0,
+        zenithal_hourly_rate: 100,
+    },
+    Shower {
+        name: "Orionids",
+        radiant: EquatorialPosition {
+            right_ascension: 95.0,
+            declination: 16.0,
+        },
+        start_longitude: 189.0,
+        peak_longitude: 208.0,
+        end_longitude: 225.0,
+        zenithal_hourly_rate: 20,
+    },
+    Shower {
+        name: "Leonids",
+        radiant: EquatorialPosition {
+            right_ascension: 152.0,
+            declination: 22.0,
+        },
+        start_longitude: 224.0,
+        peak_longitude: 235.27,
+        end_longitude: 248.0,
+        zenithal_hourly_rate: 15,
+    },
+    Shower {
+        name: "Geminids",
+        radiant: EquatorialPosition {
+            right_ascension: 112.0,
+            declination: 33.0,
+        },
+        start_longitude: 252.0,
+        peak_longitude: 262.2,
+        end_longitude: 268.0,
+        zenithal_hourly_rate: 150,
+    },
+    Shower {
+        name: "Ursids",
+        radiant: EquatorialPosition {
+            right_ascension: 217.0,
+            declination: 76.0,
+        },
+        start_longitude: 265.0,
+        peak_longitude: 270.7,
+        end_longitude: 275.0,
+        zenithal_hourly_rate: 10,
+    },
+];
+
+/// One year's return of a meteor shower.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShowerActivity {
+    pub shower: &'static Shower,
+    /// When the shower is active.
+    pub active: Interval,
+    pub peak: rtclock::Timestamp,
+}
+
+/// How good a night is for watching a meteor shower.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rating {
+    Poor,
+    Fair,
+    Good,
+    Excellent,
+}
+
+impl Rating {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Rating::Poor => "poor",
+            Rating::Fair => "fair",
+            Rating::Good => "good",
+            Rating::Excellent => "excellent",
+        }
+    }
+}
+
+/// Viewing conditions for a shower on the night of its peak.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewingConditions {
+    /// The best time to watch: when the radiant is high and moonlight is at
+    /// its least. `None` if the radiant doesn't rise while it's dark, in which
+    /// case the rating is poor.
+    pub best_time: Option<rtclock::Timestamp>,
+    /// The radiant's altitude in degrees at the best time.
+    pub radiant_altitude: f64,
+    /// Illuminated fraction of the moon [0, 1] that night.
+    pub moon_illumination: f64,
+    /// How much the moon lights up the sky at the best time: its illuminated
+    /// fraction if it's above the horizon, or 0 if it isn't.
+    pub moonlight: f64,
+    /// Roughly the fraction [0, 1] of the zenithal hourly rate that can be
+    /// seen at the best time. The rate falls off with the sine of the
+    /// radiant's altitude, and moonlight drowns out the fainter meteors.
+    pub score: f64,
+    /// The score, in words.
+    pub rating: Rating,
+}
+
+/// Gets the meteor shower that's active at `instant`, or the next one to
+/// start if none is. When several overlap, the one whose peak is nearest is
+/// picked.
+pub fn get_shower(instant: impl Into<rtclock::Timestamp>) -> ShowerActivity {
+    let days = days_since_j2000(instant.into());
+    let longitude = sun::ecliptic_position_at(days).longitude;
+
+    // How far (in degrees) the sun is past each shower's start
+    let elapsed = |shower: &Shower| normalize_degrees(longitude - shower.start_longitude);
+    let is_active = |shower: &Shower| {
+        elapsed(shower) <= normalize_degrees(shower.end_longitude - shower.start_longitude)
+    };
+    let to_peak = |shower: &Shower| normalize_degrees_signed(shower.peak_longitude - longitude);
+
+    let shower = SHOWERS
+        .iter()
+        .filter(|shower| is_active(shower))
+        .min_by(|a, b| to_peak(a).abs().total_cmp(&to_peak(b).abs()))
+        .unwrap_or_else(|| {
+            SHOWERS
+                .iter()
+                .max_by(|a, b| elapsed(a).total_cmp(&elapsed(b)))
+                .unwrap()
+        });
+
+    let start_estimate = if is_active(shower) {
+        days - elapsed(shower) / SOLAR_LONGITUDE_RATE
+    } else {
+        days + (360.0 - elapsed(shower)) / SOLAR_LONGITUDE_RATE
+    };
+    let start = solar_longitude_days(shower.start_longitude, start_estimate);
+    // Finds when the sun reaches `target` after the start of the shower
+    let after_start = |target: f64| {
+        let estimate =
+            start + normalize_degrees(target - shower.start_longitude) / SOLAR_LONGITUDE_RATE;
+        timestamp_from_days_since_j2000(solar_longitude_days(target, estimate))
+    };
+
+    ShowerActivity {
+        shower,
+        active: Interval {
+            start: timestamp_from_days_since_j2000(start),
+            end: after_start(shower.end_longitude),
+        },
+        peak: after_start(shower.peak_longitude),
+    }
+}
+
+/// Rates the viewing conditions for a shower on the night of its peak (the
+/// one whose local midnight is nearest the peak), from the best score through
+/// the night, which takes into account the moon's phase and when it's up, and
+/// how high the radiant gets while it's dark (after nautical twilight).
+pub fn get_viewing_conditions(activity: &ShowerActivity, observer: &Observer) -> ViewingConditions {
+    let mut conditions = ViewingConditions {
+        best_time: None,
+        radiant_altitude: 0.0,
+        moon_illumination: moon::get_illumination(activity.peak),
+        moonlight: 0.0,
+        score: 0.0,
+        rating: Rating::Poor,
+    };
+    let midnight = local_midnight_days(
+        timestamp_from_days_since_j2000(days_since_j2000(activity.peak) + 0.5),
+        observer,
+    );
+    let Some((dusk, dawn)) = twilight::night(
+        timestamp_from_days_since_j2000(midnight),
+        observer,
+        twilight::NAUTICAL_ALTITUDE,
+    ) else {
+        return conditions;
+    };
+
+    let steps = libm::ceil((dawn - dusk) / NIGHT_STEP) as usize;
+    for step in 0..=steps {
+        let days = (dusk + step as f64 * NIGHT_STEP).min(dawn);
+        let radiant_altitude = activity
+            .shower
+            .radiant
+            .to_horizontal(days, observer)
+            .altitude;
+        if radiant_altitude <= 0.0 {
+            continue;
+        }
+
+        let (moon_position, _) = moon::topocentric_position_at(days, observer);
+        let moonlight = if moon_position.to_horizontal(days, observer).altitude > 0.0 {
+            conditions.moon_illumination
+        } else {
+            0.0
+        };
+
+        let score = libm::sin(radiant_altitude.to_radians()) * (1.0 - moonlight);
+        if conditions.best_time.is_none() || score > conditions.score {
+            conditions.best_time = Some(timestamp_from_days_since_j2000(days));
+            conditions.radiant_altitude = radiant_altitude;
+            conditions.moonlight = moonlight;
+            conditions.score = score;
+        }
+    }
+
+    conditions.rating = if conditions.score >= EXCELLENT_SCORE {
+        Rating::Excellent
+    } else if conditions.score >= GOOD_SCORE {
+        Rating::Good
+    } else if conditions.score >= FAIR_SCORE {
+        Rating::Fair
+    } else {
+        Rating::Poor
+    };
+    conditions
+}
@@ -0,0 +1,882 @@
+use crate::calendar::search::{self, Direction, ExtremumKind, Search};
+use crate::calendar::{
+    EARTH_RADIUS_KM, EclipticPosition, EquatorialPosition, HorizontalPosition, Observer, asin_deg,
+    atan2_deg, cos_deg, days_from_julian_ephemeris_day, days_since_j2000, ephemeris_days,
+    horizon_dip, local_hour_angle, local_midnight_days, normalize_degrees, nutation_in_longitude,
+    sin_deg, sun, timestamp_from_days_since_j2000,
+};
+use crate::rtclock;
+
+/// Gets the phase of the moon as a float [0, 1), representing the fraction of
+/// the way through the current lunar cycle from new to full to new again. Note
+/// that this is *not* the same as the fractional illumination.
+///
+/// The phase is the moon's elongation from the sun in ecliptic longitude, so
+/// it follows the true (rather than mean) lunation.
+pub fn get_phase(instant: impl Into<rtclock::Timestamp>) -> f64 {
+    phase_at(days_since_j2000(instant.into()))
+}
+
+pub(crate) fn phase_at(days: f64) -> f64 {
+    let elongation =
+        ecliptic_position_at(days).longitude - sun::ecliptic_position_at(days).longitude;
+    normalize_degrees(elongation) / 360.0
+}
+
+/// Gets the phase of the moon like `get_phase`, but from its mean elongation
+/// and the largest periodic terms, rather than the full series for the sun
+/// and moon's positions. This is within 0.6° of elongation, or a little over
+/// an hour, of the true phase, for a fraction of the work on chips without
+/// an FPU.
+///
+/// See Meeus, "Astronomical Algorithms", eq. 48.4.
+pub fn get_approximate_phase(instant: impl Into<rtclock::Timestamp>) -> f64 {
+    let t = ephemeris_days(days_since_j2000(instant.into())) / 36525.0;
+
+    // Mean elongation, sun's mean anomaly and moon's mean anomaly
+    let elongation = 297.8501921 + 445267.1114034 * t;
+    let sun_anomaly = 357.5291092 + 35999.0502909 * t;
+    let moon_anomaly = 134.9633964 + 477198.8675055 * t;
+
+    let true_elongation = elongation + 6.289 * sin_deg(moon_anomaly) - 2.100 * sin_deg(sun_anomaly)
+        + 1.274 * sin_deg(2.0 * elongation - moon_anomaly)
+        + 0.658 * sin_deg(2.0 * elongation)
+        + 0.214 * sin_deg(2.0 * moon_anomaly)
+        + 0.110 * sin_deg(elongation);
+    normalize_degrees(true_elongation) / 360.0
+}
+
+pub fn get_phase_label(phase: f64) -> &'static str {
+    match phase {
+        _ if phase <= 1.0 / 16.0 => "new moon",
+        _ if phase <= 3.0 / 16.0 => "waxing crescent",
+        _ if phase <= 5.0 / 16.0 => "first quarter",
+        _ if phase <= 7.0 / 16.0 => "waxing gibbous",
+        _ if phase <= 9.0 / 16.0 => "full moon",
+        _ if phase <= 11.0 / 16.0 => "waning gibbous",
+        _ if phase <= 13.0 / 16.0 => "last quarter",
+        _ if phase <= 15.0 / 16.0 => "waning crescent",
+        _ => "new moon",
+    }
+}
+
+/// One of the four principal phases of the moon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrincipalPhase {
+    NewMoon,
+    FirstQuarter,
+    FullMoon,
+    LastQuarter,
+}
+
+impl PrincipalPhase {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PrincipalPhase::NewMoon => "new moon",
+            PrincipalPhase::FirstQuarter => "first quarter",
+            PrincipalPhase::FullMoon => "full moon",
+            PrincipalPhase::LastQuarter => "last quarter",
+        }
+    }
+
+    fn from_quarter(quarter: i64) -> Self {
+        match quarter.rem_euclid(4) {
+            0 => PrincipalPhase::NewMoon,
+            1 => PrincipalPhase::FirstQuarter,
+            2 => PrincipalPhase::FullMoon,
+            _ => PrincipalPhase::LastQuarter,
+        }
+    }
+}
+
+/// An instant at which the moon reaches a principal phase.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PhaseEvent {
+    pub phase: PrincipalPhase,
+    pub instant: rtclock::Timestamp,
+}
+
+impl PhaseEvent {
+    /// Whether this is a new or full moon close to perigee (a supermoon) or
+    /// apogee (a micromoon). Quarters are never either.
+    pub fn apsis(&self) -> Option<Apsis> {
+        if !matches!(
+            self.phase,
+            PrincipalPhase::NewMoon | PrincipalPhase::FullMoon
+        ) {
+            return None;
+        }
+        match get_distance(self.instant) {
+            distance if distance < SUPERMOON_DISTANCE_KM => Some(Apsis::Perigee),
+            distance if distance > MICROMOON_DISTANCE_KM => Some(Apsis::Apogee),
+            _ => None,
+        }
+    }
+
+    /// Like `PrincipalPhase::label`, but calls out supermoons and micromoons.
+    pub fn label(&self) -> &'static str {
+        match (self.phase, self.apsis()) {
+            (PrincipalPhase::NewMoon, Some(Apsis::Perigee)) => "new supermoon",
+            (PrincipalPhase::NewMoon, Some(Apsis::Apogee)) => "new micromoon",
+            (PrincipalPhase::FullMoon, Some(Apsis::Perigee)) => "full supermoon",
+            (PrincipalPhase::FullMoon, Some(Apsis::Apogee)) => "full micromoon",
+            (phase, _) => phase.label(),
+        }
+    }
+}
+
+/// Iterator over successive principal phases, returned by `get_next_phases`.
+pub struct NextPhases {
+    after: rtclock::Timestamp,
+    quarter: i64,
+}
+
+impl Iterator for NextPhases {
+    type Item = PhaseEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let event = PhaseEvent {
+                phase: PrincipalPhase::from_quarter(self.quarter),
+                instant: timestamp_from_days_since_j2000(principal_phase_days(self.quarter)),
+            };
+            self.quarter += 1;
+            if event.instant > self.after {
+                return Some(event);
+            }
+        }
+    }
+}
+
+/// Mean length of a lunation in days.
+pub(crate) const SYNODIC_MONTH_DAYS: f64 = 29.530588861;
+
+/// Days since J2000.0 of the mean new moon of lunation 0 (Jan 6, 2000).
+pub(crate) const LUNATION_EPOCH_DAYS: f64 = 5.09766;
+
+/// Gets the principal phases of the moon occurring after `instant`, in order.
+/// The iterator is unbounded, so use `take` to get the next N.
+///
+/// Instants are accurate to a minute or so. See Meeus, "Astronomical
+/// Algorithms", ch. 49.
+pub fn get_next_phases(instant: impl Into<rtclock::Timestamp>) -> NextPhases {
+    let instant = instant.into();
+    let lunations = (days_since_j2000(instant) - LUNATION_EPOCH_DAYS) / SYNODIC_MONTH_DAYS;
+    NextPhases {
+        after: instant,
+        // Start a quarter early, as the true phase can precede the mean one
+        quarter: libm::floor(lunations * 4.0) as i64 - 1,
+    }
+}
+
+/// Periodic corrections to the time of new moon: the coefficient in days, the
+/// power of the eccentricity factor E, then multiples of M, M', F and Ω.
+#[rustfmt::skip]
+const NEW_MOON_TERMS: [(f64, i32, i8, i8, i8, i8); 25] = [
+    (-0.40720, 0, 0, 1, 0, 0),
+    (0.17241, 1, 1, 0, 0, 0),
+    (0.01608, 0, 0, 2, 0, 0),
+    (0.01039, 0, 0, 0, 2, 0),
+    (0.00739, 1, -1, 1, 0, 0),
+    (-0.00514, 1, 1, 1, 0, 0),
+    (0.00208, 2, 2, 0, 0, 0),
+    (-0.00111, 0, 0, 1, -2, 0),
+    (-0.00057, 0, 0, 1, 2, 0),
+    (0.00056, 1, 1, 2, 0, 0),
+    (-0.00042, 0, 0, 3, 0, 0),
+    (0.00042, 1, 1, 0, 2, 0),
+    (0.00038, 1, 1, 0, -2, 0),
+    (-0.00024, 1, -1, 2, 0, 0),
+    (-0.00017, 0, 0, 0, 0, 1),
+    (-0.00007, 0, 2, 1, 0, 0),
+    (0.00004, 0, 0, 2, -2, 0),
+    (0.00004, 0, 3, 0, 0, 0),
+    (0.00003, 0, 1, 1, -2, 0),
+    (0.00003, 0, 0, 2, 2, 0),
+    (-0.00003, 0, 1, 1, 2, 0),
+    (0.00003, 0, -1, 1, 2, 0),
+    (-0.00002, 0, -1, 1, -2, 0),
+    (-0.00002, 0, 1, 3, 0, 0),
+    (0.00002, 0, 0, 4, 0, 0),
+];
+
+/// Periodic corrections to the time of full moon, as for `NEW_MOON_TERMS`.
+#[rustfmt::skip]
+const FULL_MOON_TERMS: [(f64, i32, i8, i8, i8, i8); 25] = [
+    (-0.40614, 0, 0, 1, 0, 0),
+    (0.17302, 1, 1, 0, 0, 0),
+    (0.01614, 0, 0, 2, 0, 0),
+    (0.01043, 0, 0, 0, 2, 0),
+    (0.00734, 1, -1, 1, 0, 0),
+    (-0.00515, 1, 1, 1, 0, 0),
+    (0.00209, 2, 2, 0, 0, 0),
+    (-0.00111, 0, 0, 1, -2, 0),
+    (-0.00057, 0, 0, 1, 2, 0),
+    (0.00056, 1, 1, 2, 0, 0),
+    (-0.00042, 0, 0, 3, 0, 0),
+    (0.00042, 1, 1, 0, 2, 0),
+    (0.00038, 1, 1, 0, -2, 0),
+    (-0.00024, 1, -1, 2, 0, 0),
+    (-0.00017, 0, 0, 0, 0, 1),
+    (-0.00007, 0, 2, 1, 0, 0),
+    (0.00004, 0, 0, 2, -2, 0),
+    (0.00004, 0, 3, 0, 0, 0),
+    (0.00003, 0, 1, 1, -2, 0),
+    (0.00003, 0, 0, 2, 2, 0),
+    (-0.00003, 0, 1, 1, 2, 0),
+    (0.00003, 0, -1, 1, 2, 0),
+    (-0.00002, 0, -1, 1, -2, 0),
+    (-0.00002, 0, 1, 3, 0, 0),
+    (0.00002, 0, 0, 4, 0, 0),
+];
+
+/// Periodic corrections to the time of first and last quarter, as for
+/// `NEW_MOON_TERMS`.
+#[rustfmt::skip]
+const QUARTER_TERMS: [(f64, i32, i8, i8, i8, i8); 25] = [
+    (-0.62801, 0, 0, 1, 0, 0),
+    (0.17172, 1, 1, 0, 0, 0),
+    (-0.01183, 1, 1, 1, 0, 0),
+    (0.00862, 0, 0, 2, 0, 0),
+    (0.00804, 0, 0, 0, 2, 0),
+    (0.00454, 1, -1, 1, 0, 0),
+    (0.00204, 2, 2, 0, 0, 0),
+    (-0.00180, 0, 0, 1, -2, 0),
+    (-0.00070, 0, 0, 1, 2, 0),
+    (-0.00040, 0, 0, 3, 0, 0),
+    (-0.00034, 1, -1, 2, 0, 0),
+    (0.00032, 1, 1, 0, 2, 0),
+    (0.00032, 1, 1, 0, -2, 0),
+    (-0.00028, 2, 2, 1, 0, 0),
+    (0.00027, 1, 1, 2, 0, 0),
+    (-0.00017, 0, 0, 0, 0, 1),
+    (-0.00005, 0, -1, 1, -2, 0),
+    (0.00004, 0, 0, 2, 2, 0),
+    (-0.00004, 0, 1, 1, 2, 0),
+    (0.00004, 0, -2, 1, 0, 0),
+    (0.00003, 0, 1, 1, -2, 0),
+    (0.00003, 0, 3, 0, 0, 0),
+    (0.00002, 0, 0, 2, -2, 0),
+    (0.00002, 0, -1, 1, 2, 0),
+    (-0.00002, 0, 1, 3, 0, 0),
+];
+
+/// Planetary perturbations common to all phases: the coefficient in
+/// 1e-6 days, then the argument's value at k = 0 and rate per lunation.
+#[rustfmt::skip]
+const PLANETARY_TERMS: [(f64, f64, f64); 13] = [
+    (165.0, 251.88, 0.016321),
+    (164.0, 251.83, 26.651886),
+    (126.0, 349.42, 36.412478),
+    (110.0, 84.66, 18.206239),
+    (62.0, 141.74, 53.303771),
+    (60.0, 207.14, 2.453732),
+    (56.0, 154.84, 7.306860),
+    (47.0, 34.52, 27.261239),
+    (42.0, 207.19, 0.121824),
+    (40.0, 291.34, 1.844379),
+    (37.0, 161.72, 24.198154),
+    (35.0, 239.56, 25.513099),
+    (23.0, 331.55, 3.592518),
+];
+
+/// Gets the time of the principal phase numbered `quarter` (four per
+/// lunation, starting from the new moon of Jan 6, 2000), in days since J2000.0.
+fn principal_phase_days(quarter: i64) -> f64 {
+    let k = quarter as f64 / 4.0;
+    let t = k / 1236.85;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let mean_jde = 2451550.09766 + SYNODIC_MONTH_DAYS * k + 0.00015437 * t2 - 0.000000150 * t3
+        + 0.00000000073 * t4;
+
+    let eccentricity = 1.0 - 0.002516 * t - 0.0000074 * t2;
+    let sun_anomaly = 2.5534 + 29.10535670 * k - 0.0000014 * t2 - 0.00000011 * t3;
+    let moon_anomaly =
+        201.5643 + 385.81693528 * k + 0.0107582 * t2 + 0.00001238 * t3 - 0.000000058 * t4;
+    let latitude_argument =
+        160.7108 + 390.67050284 * k - 0.0016118 * t2 - 0.00000227 * t3 + 0.000000011 * t4;
+    let node = 124.7746 - 1.56375588 * k + 0.0020672 * t2 + 0.00000215 * t3;
+
+    let phase = PrincipalPhase::from_quarter(quarter);
+    let terms = match phase {
+        PrincipalPhase::NewMoon => &NEW_MOON_TERMS,
+        PrincipalPhase::FullMoon => &FULL_MOON_TERMS,
+        PrincipalPhase::FirstQuarter | PrincipalPhase::LastQuarter => &QUARTER_TERMS,
+    };
+
+    let mut correction = 0.0;
+    for &(coefficient, e_power, m, mp, f, omega) in terms {
+        let argument = m as f64 * sun_anomaly
+            + mp as f64 * moon_anomaly
+            + f as f64 * latitude_argument
+            + omega as f64 * node;
+        correction += coefficient * libm::pow(eccentricity, e_power as f64) * sin_deg(argument);
+    }
+
+    // Quarters are offset from their mean positions in the series above
+    let quarter_offset = 0.00306 - 0.00038 * eccentricity * cos_deg(sun_anomaly)
+        + 0.00026 * cos_deg(moon_anomaly)
+        - 0.00002 * cos_deg(moon_anomaly - sun_anomaly)
+        + 0.00002 * cos_deg(moon_anomaly + sun_anomaly)
+        + 0.00002 * cos_deg(2.0 * latitude_argument);
+    match phase {
+        PrincipalPhase::FirstQuarter => correction += quarter_offset,
+        PrincipalPhase::LastQuarter => correction -= quarter_offset,
+        _ => {}
+    }
+
+    correction += 0.000325 * sin_deg(299.77 + 0.107408 * k - 0.009173 * t2);
+    for (coefficient, epoch, rate) in PLANETARY_TERMS {
+        correction += coefficient * 1e-6 * sin_deg(epoch + rate * k);
+    }
+
+    days_from_julian_ephemeris_day(mean_jde + correction)
+}
+
+/// Gets the illuminated fraction of the moon's disk [0, 1] at an instant,
+/// from the phase angle between the sun and the earth as seen from the moon.
+///
+/// See Meeus, "Astronomical Algorithms", ch. 48.
+pub fn get_illumination(instant: impl Into<rtclock::Timestamp>) -> f64 {
+    let days = days_since_j2000(instant.into());
+    let moon = ecliptic_position_at(days);
+    let sun = sun::ecliptic_position_at(days);
+
+    // Geocentric elongation of the moon from the sun, then the phase angle
+    let cos_elongation = cos_deg(moon.latitude) * cos_deg(moon.longitude - sun.longitude);
+    let sin_elongation = libm::sqrt(1.0 - cos_elongation * cos_elongation);
+    let phase_angle = atan2_deg(
+        sun.distance * sin_elongation,
+        moon.distance - sun.distance * cos_elongation,
+    );
+
+    (1.0 + cos_deg(phase_angle)) / 2.0
+}
+
+/// Gets the position angle of the moon's bright limb in degrees [0, 360): the
+/// direction from the centre of its disk to the middle of the lit edge,
+/// measured from celestial north through east. This points towards the sun.
+///
+/// See Meeus, "Astronomical Algorithms", ch. 48.
+pub fn get_bright_limb_angle(instant: impl Into<rtclock::Timestamp>) -> f64 {
+    let days = days_since_j2000(instant.into());
+    let moon = ecliptic_position_at(days).to_equatorial(days);
+    let sun = sun::position_at(days);
+    let right_ascension_difference = sun.right_ascension - moon.right_ascension;
+    normalize_degrees(atan2_deg(
+        cos_deg(sun.declination) * sin_deg(right_ascension_difference),
+        sin_deg(sun.declination) * cos_deg(moon.declination)
+            - cos_deg(sun.declination)
+                * sin_deg(moon.declination)
+                * cos_deg(right_ascension_difference),
+    ))
+}
+
+/// Gets the direction of the moon's bright limb as it appears to an observer,
+/// in degrees [0, 360) measured from straight up (towards the zenith) through
+/// the left. So a crescent lit from below-right, as the evening moon is from
+/// northern latitudes, is at about 225°.
+pub fn get_bright_limb_tilt(instant: impl Into<rtclock::Timestamp>, observer: &Observer) -> f64 {
+    let instant = instant.into();
+    let days = days_since_j2000(instant);
+    let (position, _) = topocentric_position_at(days, observer);
+
+    // The parallactic angle, between the directions to the celestial pole and
+    // the zenith. See Meeus ch. 14.
+    let hour_angle = local_hour_angle(&position, days, observer);
+    let parallactic_angle = atan2_deg(
+        sin_deg(hour_angle),
+        libm::tan(observer.latitude.to_radians()) * cos_deg(position.declination)
+            - sin_deg(position.declination) * cos_deg(hour_angle),
+    );
+    normalize_degrees(get_bright_limb_angle(instant) - parallactic_angle)
+}
+
+/// Illuminated fraction at evenly spaced phases, (1 - cos(2π phase)) / 2. This
+/// ignores the moon's latitude and distance, which only affect the fraction by
+/// a few tenths of a percent.
+#[rustfmt::skip]
+const ILLUMINATION_TABLE: [f64; 32] = [
+    0.0000,
+    0.0096,
+    0.0381,
+    0.0843,
+    0.1464,
+    0.2222,
+    0.3087,
+    0.4025,
+    0.5000,
+    0.5975,
+    0.6913,
+    0.7778,
+    0.8536,
+    0.9157,
+    0.9619,
+    0.9904,
+    1.0000,
+    0.9904,
+    0.9619,
+    0.9157,
+    0.8536,
+    0.7778,
+    0.6913,
+    0.5975,
+    0.5000,
+    0.4025,
+    0.3087,
+    0.2222,
+    0.1464,
+    0.0843,
+    0.0381,
+    0.0096,
+];
+
+/// Calculates the illuminated fraction from the moon's phase using a lookup
+/// table. Together with `get_approximate_phase`, this is a cheaper
+/// alternative to `get_illumination` for chips without an FPU, and is within
+/// half a percent of it.
+pub fn get_illumination_from_phase(phase: f64) -> f64 {
+    let fractional_index = phase * ILLUMINATION_TABLE.len() as f64;
+
+    let low_index = fractional_index as usize % ILLUMINATION_TABLE.len();
+    let high_index = (low_index + 1) % ILLUMINATION_TABLE.len();
+
+    let low_val = ILLUMINATION_TABLE[low_index];
+    let high_val = ILLUMINATION_TABLE[high_index];
+    let a = fractional_index - (low_index as f64);
+
+    low_val * (1.0 - a) + high_val * a
+}
+
+/// New and full moons closer than this are popularly called supermoons.
+const SUPERMOON_DISTANCE_KM: f64 = 360_000.0;
+
+/// New and full moons further away than this are called micromoons.
+const MICROMOON_DISTANCE_KM: f64 = 405_000.0;
+
+/// How the moon's distance is searched for perigee and apogee, which are
+/// about two weeks apart. Sampled twice a day.
+const APSIS_SEARCH: Search = Search {
+    step: 43_200,
+    tolerance: 1,
+};
+
+/// The points in the moon's orbit closest to and furthest from the earth.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Apsis {
+    Perigee,
+    Apogee,
+}
+
+impl Apsis {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Apsis::Perigee => "perigee",
+            Apsis::Apogee => "apogee",
+        }
+    }
+}
+
+/// An instant at which the moon reaches perigee or apogee.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ApsisEvent {
+    pub apsis: Apsis,
+    pub instant: rtclock::Timestamp,
+    /// Distance between the centres of the earth and moon in kilometres.
+    pub distance: f64,
+}
+
+/// Iterator over successive perigees and apogees, returned by
+/// `get_next_apsides`.
+pub struct NextApsides {
+    after: rtclock::Timestamp,
+    extrema: search::Extrema<fn(rtclock::Timestamp) -> f64>,
+}
+
+impl Iterator for NextApsides {
+    type Item = ApsisEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let after = self.after;
+        self.extrema
+            .find(|extremum| extremum.instant > after)
+            .map(|extremum| ApsisEvent {
+                apsis: match extremum.kind {
+                    ExtremumKind::Minimum => Apsis::Perigee,
+                    ExtremumKind::Maximum => Apsis::Apogee,
+                },
+                instant: extremum.instant,
+                distance: extremum.value,
+            })
+    }
+}
+
+/// Gets the distance between the centres of the earth and moon in
+/// kilometres, which varies between about 356,000 and 407,000.
+pub fn get_distance(instant: impl Into<rtclock::Timestamp>) -> f64 {
+    ecliptic_position_at(days_since_j2000(instant.into())).distance
+}
+
+/// Gets the moon's apparent angular diameter in degrees, as seen from the
+/// centre of the earth. This is about half a degree.
+pub fn get_angular_diameter(instant: impl Into<rtclock::Timestamp>) -> f64 {
+    2.0 * asin_deg(MOON_RADIUS_KM / get_distance(instant))
+}
+
+/// Gets the perigees and apogees occurring after `instant`, in order. The
+/// iterator is unbounded, so use `take` to get the next N.
+pub fn get_next_apsides(instant: impl Into<rtclock::Timestamp>) -> NextApsides {
+    let instant = instant.into();
+    NextApsides {
+        after: instant,
+        // Start a step early, in case there's an extremum right at `instant`
+        extrema: APSIS_SEARCH.extrema(get_distance, instant - APSIS_SEARCH.step, None),
+    }
+}
+
+pub(crate) const MOON_RADIUS_KM: f64 = 1737.4;
+
+/// Refraction at the horizon, in degrees.
+const HORIZON_REFRACTION: f64 = 0.5667;
+
+/// How the moon's altitude and hour angle are searched for rise, set and
+/// transit. It can't rise and set within an hour.
+const EVENT_SEARCH: Search = Search {
+    step: 3600,
+    tolerance: 1,
+};
+
+/// The moon's daily events for an observer. Each is `None` on days when it
+/// doesn't happen, which for rise, set and transit is roughly once a month as
+/// the moon's daily cycle is about 50 minutes longer than a day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MoonEvents {
+    /// When the moon's upper limb rises above the apparent horizon.
+    pub rise: Option<rtclock::Timestamp>,
+    /// When the moon crosses the observer's meridian.
+    pub transit: Option<rtclock::Timestamp>,
+    /// When the moon's upper limb sets below the apparent horizon.
+    pub set: Option<rtclock::Timestamp>,
+}
+
+/// Gets the moon's apparent position as seen from the centre of the earth.
+pub fn get_position(instant: impl Into<rtclock::Timestamp>) -> EquatorialPosition {
+    let days = days_since_j2000(instant.into());
+    ecliptic_position_at(days).to_equatorial(days)
+}
+
+/// Gets the moon's apparent position as seen by an observer on the earth's
+/// surface. This differs from the geocentric position by up to a degree, due
+/// to the moon's parallax.
+pub fn get_topocentric_position(
+    instant: impl Into<rtclock::Timestamp>,
+    observer: &Observer,
+) -> EquatorialPosition {
+    topocentric_position_at(days_since_j2000(instant.into()), observer).0
+}
+
+/// Gets the moon's altitude and azimuth as seen by an observer, without
+/// refraction.
+pub fn get_horizontal_position(
+    instant: impl Into<rtclock::Timestamp>,
+    observer: &Observer,
+) -> HorizontalPosition {
+    let days = days_since_j2000(instant.into());
+    topocentric_position_at(days, observer)
+        .0
+        .to_horizontal(days, observer)
+}
+
+/// Gets moonrise, moonset and transit on the day containing `instant`. Days
+/// are defined as for `sun::get_events`.
+pub fn get_events(instant: impl Into<rtclock::Timestamp>, observer: &Observer) -> MoonEvents {
+    let start = timestamp_from_days_since_j2000(local_midnight_days(instant.into(), observer));
+    let end = start + 86_400;
+    let horizon = -HORIZON_REFRACTION - horizon_dip(observer);
+
+    // Altitude of the upper limb above the apparent horizon
+    let limb_altitude = |instant: rtclock::Timestamp| {
+        let days = days_since_j2000(instant);
+        let (position, distance) = topocentric_position_at(days, observer);
+        position.to_horizontal(days, observer).altitude + asin_deg(MOON_RADIUS_KM / distance)
+            - horizon
+    };
+    let hour_angle = |instant: rtclock::Timestamp| {
+        let days = days_since_j2000(instant);
+        let (position, _) = topocentric_position_at(days, observer);
+        local_hour_angle(&position, days, observer)
+    };
+
+    let mut events = MoonEvents {
+        rise: None,
+        transit: None,
+        set: None,
+    };
+    for crossing in EVENT_SEARCH.crossings(limb_altitude, 0.0, start, Some(end)) {
+        let event = match crossing.direction {
+            Direction::Rising => &mut events.rise,
+            Direction::Falling => &mut events.set,
+        };
+        event.get_or_insert(crossing.instant);
+    }
+    // The hour angle also jumps from +180 to -180 on the other side of the
+    // sky, but that's a fall rather than a rise
+    events.transit = EVENT_SEARCH
+        .crossings(hour_angle, 0.0, start, Some(end))
+        .find(|crossing| crossing.direction == Direction::Rising)
+        .map(|crossing| crossing.instant);
+
+    events
+}
+
+/// Gets the moon's topocentric position and distance in kilometres. See Meeus,
+/// "Astronomical Algorithms", ch. 40.
+pub(crate) fn topocentric_position_at(days: f64, observer: &Observer) -> (EquatorialPosition, f64) {
+    let ecliptic = ecliptic_position_at(days);
+    let geocentric = ecliptic.to_equatorial(days);
+
+    // Observer's geocentric position, accounting for the earth's flattening
+    let u = libm::atan(0.99664719 * libm::tan(observer.latitude.to_radians())).to_degrees();
+    let height = observer.elevation / (EARTH_RADIUS_KM * 1000.0);
+    let rho_sin = 0.99664719 * sin_deg(u) + height * sin_deg(observer.latitude);
+    let rho_cos = cos_deg(u) + height * cos_deg(observer.latitude);
+
+    let sin_parallax = EARTH_RADIUS_KM / ecliptic.distance;
+    let hour_angle = local_hour_angle(&geocentric, days, observer);
+
+    let denominator =
+        cos_deg(geocentric.declination) - rho_cos * sin_parallax * cos_deg(hour_angle);
+    let delta_right_ascension =
+        atan2_deg(-rho_cos * sin_parallax * sin_deg(hour_angle), denominator);
+    let declination = atan2_deg(
+        (sin_deg(geocentric.declination) - rho_sin * sin_parallax) * cos_deg(delta_right_ascension),
+        denominator,
+    );
+
+    // Distance from the observer, from the geocentric position (in units of
+    // the moon's distance, relative to the meridian) less the observer's
+    let x = cos_deg(geocentric.declination) * cos_deg(hour_angle) - rho_cos * sin_parallax;
+    let y = cos_deg(geocentric.declination) * sin_deg(hour_angle);
+    let z = sin_deg(geocentric.declination) - rho_sin * sin_parallax;
+    let distance = ecliptic.distance * libm::sqrt(x * x + y * y + z * z);
+
+    (
+        EquatorialPosition {
+            right_ascension: normalize_degrees(geocentric.right_ascension + delta_right_ascension),
+            declination,
+        },
+        distance,
+    )
+}
+
+/// Periodic terms for the moon's longitude and distance: multiples of D, M, M'
+/// and F, then the sine coefficient for longitude (1e-6 degrees) and the
+/// cosine coefficient for distance (1e-3 km).
+///
+/// See Meeus, "Astronomical Algorithms", table 47.A. This is itself a
+/// truncation of the ELP-2000/82 theory, good to about 10" in longitude.
+#[rustfmt::skip]
+const LONGITUDE_DISTANCE_TERMS: [(i8, i8, i8, i8, i32, i32); 60] = [
+    (0, 0, 1, 0, 6288774, -20905355),
+    (2, 0, -1, 0, 1274027, -3699111),
+    (2, 0, 0, 0, 658314, -2955968),
+    (0, 0, 2, 0, 213618, -569925),
+    (0, 1, 0, 0, -185116, 48888),
+    (0, 0, 0, 2, -114332, -3149),
+    (2, 0, -2, 0, 58793, 246158),
+    (2, -1, -1, 0, 57066, -152138),
+    (2, 0, 1, 0, 53322, -170733),
+    (2, -1, 0, 0, 45758, -204586),
+    (0, 1, -1, 0, -40923, -129620),
+    (1, 0, 0, 0, -34720, 108743),
+    (0, 1, 1, 0, -30383, 104755),
+    (2, 0, 0, -2, 15327, 10321),
+    (0, 0, 1, 2, -12528, 0),
+    (0, 0, 1, -2, 10980, 79661),
+    (4, 0, -1, 0, 10675, -34782),
+    (0, 0, 3, 0, 10034, -23210),
+    (4, 0, -2, 0, 8548, -21636),
+    (2, 1, -1, 0, -7888, 24208),
+    (2, 1, 0, 0, -6766, 30824),
+    (1, 0, -1, 0, -5163, -8379),
+    (1, 1, 0, 0, 4987, -16675),
+    (2, -1, 1, 0, 4036, -12831),
+    (2, 0, 2, 0, 3994, -10445),
+    (4, 0, 0, 0, 3861, -11650),
+    (2, 0, -3, 0, 3665, 14403),
+    (0, 1, -2, 0, -2689, -7003),
+    (2, 0, -1, 2, -2602, 0),
+    (2, -1, -2, 0, 2390, 10056),
+    (1, 0, 1, 0, -2348, 6322),
+    (2, -2, 0, 0, 2236, -9884),
+    (0, 1, 2, 0, -2120, 5751),
+    (0, 2, 0, 0, -2069, 0),
+    (2, -2, -1, 0, 2048, -4950),
+    (2, 0, 1, -2, -1773, 4130),
+    (2, 0, 0, 2, -1595, 0),
+    (4, -1, -1, 0, 1215, -3958),
+    (0, 0, 2, 2, -1110, 0),
+    (3, 0, -1, 0, -892, 3258),
+    (2, 1, 1, 0, -810, 2616),
+    (4, -1, -2, 0, 759, -1897),
+    (0, 2, -1, 0, -713, -2117),
+    (2, 2, -1, 0, -700, 2354),
+    (2, 1, -2, 0, 691, 0),
+    (2, -1, 0, -2, 596, 0),
+    (4, 0, 1, 0, 549, -1423),
+    (0, 0, 4, 0, 537, -1117),
+    (4, -1, 0, 0, 520, -1571),
+    (1, 0, -2, 0, -487, -1739),
+    (2, 1, 0, -2, -399, 0),
+    (0, 0, 2, -2, -381, -4421),
+    (1, 1, 1, 0, 351, 0),
+    (3, 0, -2, 0, -340, 0),
+    (4, 0, -3, 0, 330, 0),
+    (2, -1, 2, 0, 327, 0),
+    (0, 2, 1, 0, -323, 1165),
+    (1, 1, -1, 0, 299, 0),
+    (2, 0, 3, 0, 294, 0),
+    (2, 0, -1, -2, 0, 8752),
+];
+
+/// Periodic terms for the moon's latitude: multiples of D, M, M' and F, then
+/// the sine coefficient (1e-6 degrees). See Meeus table 47.B.
+#[rustfmt::skip]
+const LATITUDE_TERMS: [(i8, i8, i8, i8, i32); 60] = [
+    (0, 0, 0, 1, 5128122),
+    (0, 0, 1, 1, 280602),
+    (0, 0, 1, -1, 277693),
+    (2, 0, 0, -1, 173237),
+    (2, 0, -1, 1, 55413),
+    (2, 0, -1, -1, 46271),
+    (2, 0, 0, 1, 32573),
+    (0, 0, 2, 1, 17198),
+    (2, 0, 1, -1, 9266),
+    (0, 0, 2, -1, 8822),
+    (2, -1, 0, -1, 8216),
+    (2, 0, -2, -1, 4324),
+    (2, 0, 1, 1, 4200),
+    (2, 1, 0, -1, -3359),
+    (2, -1, -1, 1, 2463),
+    (2, -1, 0, 1, 2211),
+    (2, -1, -1, -1, 2065),
+    (0, 1, -1, -1, -1870),
+    (4, 0, -1, -1, 1828),
+    (0, 1, 0, 1, -1794),
+    (0, 0, 0, 3, -1749),
+    (0, 1, -1, 1, -1565),
+    (1, 0, 0, 1, -1491),
+    (0, 1, 1, 1, -1475),
+    (0, 1, 1, -1, -1410),
+    (0, 1, 0, -1, -1344),
+    (1, 0, 0, -1, -1335),
+    (0, 0, 3, 1, 1107),
+    (4, 0, 0, -1, 1021),
+    (4, 0, -1, 1, 833),
+    (0, 0, 1, -3, 777),
+    (4, 0, -2, 1, 671),
+    (2, 0, 0, -3, 607),
+    (2, 0, 2, -1, 596),
+    (2, -1, 1, -1, 491),
+    (2, 0, -2, 1, -451),
+    (0, 0, 3, -1, 439),
+    (2, 0, 2, 1, 422),
+    (2, 0, -3, -1, 421),
+    (2, 1, -1, 1, -366),
+    (2, 1, 0, 1, -351),
+    (4, 0, 0, 1, 331),
+    (2, -1, 1, 1, 315),
+    (2, -2, 0, -1, 302),
+    (0, 0, 1, 3, -283),
+    (2, 1, 1, -1, -229),
+    (1, 1, 0, -1, 223),
+    (1, 1, 0, 1, 223),
+    (0, 1, -2, -1, -220),
+    (2, 1, -1, -1, -220),
+    (1, 0, 1, 1, -185),
+    (2, -1, -2, -1, 181),
+    (0, 1, 2, 1, -177),
+    (4, 0, -2, -1, 176),
+    (4, -1, -1, -1, 166),
+    (1, 0, 1, -1, -164),
+    (4, 0, 1, -1, 132),
+    (1, 0, -1, -1, -119),
+    (4, -1, 0, -1, 115),
+    (2, -2, 0, 1, 107),
+];
+
+/// Gets the moon's apparent geocentric ecliptic position at `days` since
+/// J2000.0.
+pub(crate) fn ecliptic_position_at(days: f64) -> EclipticPosition {
+    let t = ephemeris_days(days) / 36525.0;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    // Mean longitude, elongation, sun's mean anomaly, moon's mean anomaly and
+    // argument of latitude
+    let mean_longitude =
+        218.3164477 + 481267.88123421 * t - 0.0015786 * t2 + t3 / 538841.0 - t4 / 65194000.0;
+    let elongation =
+        297.8501921 + 445267.1114034 * t - 0.0018819 * t2 + t3 / 545868.0 - t4 / 113065000.0;
+    let sun_anomaly = 357.5291092 + 35999.0502909 * t - 0.0001536 * t2 + t3 / 24490000.0;
+    let moon_anomaly =
+        134.9633964 + 477198.8675055 * t + 0.0087414 * t2 + t3 / 69699.0 - t4 / 14712000.0;
+    let latitude_argument =
+        93.2720950 + 483202.0175233 * t - 0.0036539 * t2 - t3 / 3526000.0 + t4 / 863310000.0;
+
+    // Perturbations by Venus and Jupiter, and from the earth's flattening
+    let a1 = 119.75 + 131.849 * t;
+    let a2 = 53.09 + 479264.290 * t;
+    let a3 = 313.45 + 481266.484 * t;
+
+    // Correction for the decreasing eccentricity of the earth's orbit, applied
+    // once per multiple of M
+    let eccentricity = 1.0 - 0.002516 * t - 0.0000074 * t2;
+    let eccentricity_factor = |m: i8| match m.abs() {
+        0 => 1.0,
+        1 => eccentricity,
+        _ => eccentricity * eccentricity,
+    };
+    let argument = |d: i8, m: i8, mp: i8, f: i8| {
+        d as f64 * elongation
+            + m as f64 * sun_anomaly
+            + mp as f64 * moon_anomaly
+            + f as f64 * latitude_argument
+    };
+
+    let mut sum_longitude = 0.0;
+    let mut sum_distance = 0.0;
+    for (d, m, mp, f, longitude, distance) in LONGITUDE_DISTANCE_TERMS {
+        let arg = argument(d, m, mp, f);
+        let e = eccentricity_factor(m);
+        sum_longitude += longitude as f64 * e * sin_deg(arg);
+        sum_distance += distance as f64 * e * cos_deg(arg);
+    }
+
+    let mut sum_latitude = 0.0;
+    for (d, m, mp, f, latitude) in LATITUDE_TERMS {
+        sum_latitude += latitude as f64 * eccentricity_factor(m) * sin_deg(argument(d, m, mp, f));
+    }
+
+    sum_longitude += 3958.0 * sin_deg(a1)
+        + 1962.0 * sin_deg(mean_longitude - latitude_argument)
+        + 318.0 * sin_deg(a2);
+    sum_latitude += -2235.0 * sin_deg(mean_longitude)
+        + 382.0 * sin_deg(a3)
+        + 175.0 * sin_deg(a1 - latitude_argument)
+        + 175.0 * sin_deg(a1 + latitude_argument)
+        + 127.0 * sin_deg(mean_longitude - moon_anomaly)
+        - 115.0 * sin_deg(mean_longitude + moon_anomaly);
+
+    EclipticPosition {
+        longitude: normalize_degrees(
+            mean_longitude + sum_longitude / 1e6 + nutation_in_longitude(days),
+        ),
+        latitude: sum_latitude / 1e6,
+        distance: 385000.56 + sum_distance / 1e3,
+    }
+}
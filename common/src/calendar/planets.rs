@@ -0,0 +1,433 @@
+mod vsop87;
+
+use crate::calendar::{
+    AU_KM, EclipticPosition, EquatorialPosition, HOUR_ANGLE_RATE, Observer, acos_deg, asin_deg,
+    atan2_deg, cos_crossing_hour_angle, cos_deg, days_since_j2000, ephemeris_days, horizon_dip,
+    local_hour_angle, local_midnight_days, normalize_degrees, normalize_degrees_signed,
+    nutation_in_longitude, sin_deg, sun, timestamp_from_days_since_j2000, twilight,
+};
+use crate::rtclock;
+
+/// Altitude of a planet's centre at rising and setting: just the refraction
+/// at the horizon, as planets are too small for their radius to matter.
+const RISE_ALTITUDE: f64 = -0.5667;
+
+/// Days taken for light to travel one AU.
+const LIGHT_TIME_DAYS_PER_AU: f64 = 0.005_775_518_3;
+
+/// Number of times the light-time correction is refined. The planets move
+/// little in the ~40 minutes light takes to reach us from Saturn, so one
+/// refinement gets well under an arcsecond.
+const LIGHT_TIME_ITERATIONS: usize = 2;
+
+/// Number of times rise, set and transit estimates are refined against the
+/// planet's updated position.
+const ITERATIONS: usize = 3;
+
+/// Interval at which planets' altitudes are sampled through the night, in
+/// days (10 minutes).
+const NIGHT_STEP: f64 = 10.0 / 1440.0;
+
+/// Altitude a planet must reach in a dark sky to count as visible. Planets
+/// any lower are lost in the murk near the horizon.
+const MIN_VISIBLE_ALTITUDE: f64 = 5.0;
+
+/// The planets visible to the naked eye.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Planet {
+    Mercury,
+    Venus,
+    Mars,
+    Jupiter,
+    Saturn,
+}
+
+impl Planet {
+    /// All planets, in order of distance from the sun.
+    pub const ALL: [Planet; 5] = [
+        Planet::Mercury,
+        Planet::Venus,
+        Planet::Mars,
+        Planet::Jupiter,
+        Planet::Saturn,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Planet::Mercury => "Mercury",
+            Planet::Venus => "Venus",
+            Planet::Mars => "Mars",
+            Planet::Jupiter => "Jupiter",
+            Planet::Saturn => "Saturn",
+        }
+    }
+
+    fn series(&self) -> &'static vsop87::Series {
+        match self {
+            Planet::Mercury => &vsop87::MERCURY,
+            Planet::Venus => &vsop87::VENUS,
+            Planet::Mars => &vsop87::MARS,
+            Planet::Jupiter => &vsop87::JUPITER,
+            Planet::Saturn => &vsop87::SATURN,
+        }
+    }
+}
+
+/// Position of a planet relative to the sun, referred to the ecliptic and
+/// equinox of date.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HeliocentricPosition {
+    /// Ecliptic longitude in degrees [0, 360).
+    pub longitude: f64,
+    /// Ecliptic latitude in degrees [-90, 90].
+    pub latitude: f64,
+    /// Distance from the sun in AU.
+    pub distance: f64,
+}
+
+/// A planet's daily events for an observer. Each is `None` on days when it
+/// doesn't happen, either because the planet doesn't rise or set at all, or
+/// because the event drifted into the next day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlanetEvents {
+    /// When the planet rises above the apparent horizon.
+    pub rise: Option<rtclock::Timestamp>,
+    /// When the planet crosses the observer's meridian.
+    pub transit: Option<rtclock::Timestamp>,
+    /// When the planet sets below the apparent horizon.
+    pub set: Option<rtclock::Timestamp>,
+}
+
+/// A planet that can be seen during the night, returned by
+/// `get_visible_tonight`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Visibility {
+    pub planet: Planet,
+    /// When the planet rises after dark, or `None` if it's already up at dusk.
+    pub rise: Option<rtclock::Timestamp>,
+    /// When the planet sets before dawn, or `None` if it's still up at dawn.
+    pub set: Option<rtclock::Timestamp>,
+    /// Apparent visual magnitude in the middle of the night.
+    pub magnitude: f64,
+}
+
+/// Gets a planet's geometric position relative to the sun.
+pub fn get_heliocentric_position(
+    planet: Planet,
+    instant: impl Into<rtclock::Timestamp>,
+) -> HeliocentricPosition {
+    heliocentric_position_at(planet.series(), days_since_j2000(instant.into()))
+}
+
+/// Gets a planet's apparent position as seen from the centre of the earth,
+/// correcting for light-time, aberration and nutation. This is accurate to
+/// about 10".
+///
+/// See Meeus, "Astronomical Algorithms", ch. 33.
+pub fn get_ecliptic_position(
+    planet: Planet,
+    instant: impl Into<rtclock::Timestamp>,
+) -> EclipticPosition {
+    geometry_at(planet, days_since_j2000(instant.into())).position
+}
+
+/// Gets a planet's apparent right ascension and declination as seen from the
+/// centre of the earth. Parallax is at most 30" (for Venus), so this is also
+/// good for observers on the surface.
+pub fn get_position(planet: Planet, instant: impl Into<rtclock::Timestamp>) -> EquatorialPosition {
+    position_at(planet, days_since_j2000(instant.into()))
+}
+
+/// Gets a planet's elongation: its angular distance from the sun, in degrees.
+/// It's positive when the planet is east of the sun, so in the evening sky,
+/// and negative when it's west of the sun in the morning sky.
+pub fn get_elongation(planet: Planet, instant: impl Into<rtclock::Timestamp>) -> f64 {
+    let days = days_since_j2000(instant.into());
+    let position = geometry_at(planet, days).position;
+    let sun = sun::ecliptic_position_at(days);
+    let longitude_difference = normalize_degrees_signed(position.longitude - sun.longitude);
+    let elongation = acos_deg(cos_deg(position.latitude) * cos_deg(longitude_difference));
+    if longitude_difference < 0.0 {
+        -elongation
+    } else {
+        elongation
+    }
+}
+
+/// Gets a planet's apparent visual magnitude, which accounts for its
+/// distance from the sun and earth and its phase (and for Saturn, the tilt of
+/// its rings).
+///
+/// See Meeus, "Astronomical Algorithms", ch. 41.
+pub fn get_magnitude(planet: Planet, instant: impl Into<rtclock::Timestamp>) -> f64 {
+    magnitude_at(planet, days_since_j2000(instant.into()))
+}
+
+/// Gets the planet's rise, set and transit on the day containing `instant`.
+/// Days are defined as for `sun::get_events`.
+///
+/// See Meeus, "Astronomical Algorithms", ch. 15.
+pub fn get_events(
+    planet: Planet,
+    instant: impl Into<rtclock::Timestamp>,
+    observer: &Observer,
+) -> PlanetEvents {
+    let start = local_midnight_days(instant.into(), observer);
+    let altitude = RISE_ALTITUDE - horizon_dip(observer);
+    let crossing_hour_angle = |position: &EquatorialPosition| {
+        let cos_hour_angle = cos_crossing_hour_angle(position, observer, altitude);
+        (-1.0..=1.0)
+            .contains(&cos_hour_angle)
+            .then(|| acos_deg(cos_hour_angle))
+    };
+
+    PlanetEvents {
+        rise: event(planet, start, observer, |position| {
+            crossing_hour_angle(position).map(|hour_angle| -hour_angle)
+        }),
+        transit: event(planet, start, observer, |_| Some(0.0)),
+        set: event(planet, start, observer, crossing_hour_angle),
+    }
+}
+
+/// Gets the planets that can be seen in a dark sky tonight, in order of
+/// distance from the sun. The night runs from the end of civil twilight in
+/// the evening to its start the next morning; before dawn, "tonight" is the
+/// night that's in progress.
+///
+/// A planet is visible if it gets at least 5° above the horizon during the
+/// night, so those lost in twilight near the sun are left out.
+pub fn get_visible_tonight(
+    instant: impl Into<rtclock::Timestamp>,
+    observer: &Observer,
+) -> impl Iterator<Item = Visibility> {
+    let night = twilight::night(instant.into(), observer, twilight::CIVIL_ALTITUDE);
+    let observer = *observer;
+    Planet::ALL
+        .into_iter()
+        .filter_map(move |planet| visibility(planet, night?, &observer))
+}
+
+/// Works out whether and when `planet` can be seen during the night from
+/// `dusk` to `dawn`.
+fn visibility(planet: Planet, (dusk, dawn): (f64, f64), observer: &Observer) -> Option<Visibility> {
+    // The planets move so little over a night that their position can be
+    // taken as fixed, which saves evaluating the series at every step
+    let midnight = (dusk + dawn) / 2.0;
+    let position = position_at(planet, midnight);
+    let horizon = RISE_ALTITUDE - horizon_dip(observer);
+    let altitude = |days: f64| position.to_horizontal(days, observer).altitude;
+
+    // Solves for the time in a step at which the planet crosses the horizon,
+    // rising (-1) or setting (1)
+    let crossing = |days: f64, direction: f64| {
+        let cos_hour_angle = cos_crossing_hour_angle(&position, observer, horizon);
+        let target = direction * acos_deg(cos_hour_angle.clamp(-1.0, 1.0));
+        let current = local_hour_angle(&position, days, observer);
+        timestamp_from_days_since_j2000(
+            days + normalize_degrees_signed(target - current) / HOUR_ANGLE_RATE,
+        )
+    };
+
+    let mut is_visible = false;
+    let mut rise = None;
+    let mut set = None;
+    let mut previous = (dusk, altitude(dusk));
+    is_visible |= previous.1 >= MIN_VISIBLE_ALTITUDE;
+
+    let steps = libm::ceil((dawn - dusk) / NIGHT_STEP) as usize;
+    for step in 1..=steps {
+        let days = (dusk + step as f64 * NIGHT_STEP).min(dawn);
+        let current = (days, altitude(days));
+        is_visible |= current.1 >= MIN_VISIBLE_ALTITUDE;
+
+        if rise.is_none() && previous.1 < horizon && current.1 >= horizon {
+            rise = Some(crossing(previous.0, -1.0));
+        }
+        if set.is_none() && previous.1 >= horizon && current.1 < horizon {
+            set = Some(crossing(previous.0, 1.0));
+        }
+
+        previous = current;
+    }
+
+    is_visible.then(|| Visibility {
+        planet,
+        rise,
+        set,
+        magnitude: magnitude_at(planet, midnight),
+    })
+}
+
+/// Iterates towards the time in the day from `start` that the planet is at
+/// the hour angle given by `target`, which may depend on its position. Returns
+/// `None` if `target` does, or if the event falls outside the day.
+fn event(
+    planet: Planet,
+    start: f64,
+    observer: &Observer,
+    target: impl Fn(&EquatorialPosition) -> Option<f64>,
+) -> Option<rtclock::Timestamp> {
+    // Start from the first time in the day that the planet would reach the
+    // target if it stayed where it is at midday
+    let position = position_at(planet, start + 0.5);
+    let mut days = start
+        + normalize_degrees(target(&position)? - local_hour_angle(&position, start, observer))
+            / HOUR_ANGLE_RATE;
+
+    for _ in 0..ITERATIONS {
+        let position = position_at(planet, days);
+        let current = local_hour_angle(&position, days, observer);
+        days += normalize_degrees_signed(target(&position)? - current) / HOUR_ANGLE_RATE;
+    }
+
+    (start..start + 1.0)
+        .contains(&days)
+        .then(|| timestamp_from_days_since_j2000(days))
+}
+
+/// A planet's apparent geocentric position along with the heliocentric
+/// positions it was derived from.
+struct Geometry {
+    /// Apparent geocentric position, with the distance in kilometres.
+    position: EclipticPosition,
+    /// The planet's heliocentric position when the light left it.
+    heliocentric: HeliocentricPosition,
+}
+
+fn position_at(planet: Planet, days: f64) -> EquatorialPosition {
+    geometry_at(planet, days).position.to_equatorial(days)
+}
+
+fn geometry_at(planet: Planet, days: f64) -> Geometry {
+    let mut light_time = 0.0;
+    let mut geometry = geometry_with_light_time(planet, days, light_time);
+    for _ in 0..LIGHT_TIME_ITERATIONS {
+        light_time = LIGHT_TIME_DAYS_PER_AU * geometry.position.distance / AU_KM;
+        geometry = geometry_with_light_time(planet, days, light_time);
+    }
+    geometry
+}
+
+/// Gets the planet's position as seen from the earth, both taken
+/// `light_time` days before `days`. Moving the earth back along with the
+/// planet accounts for aberration as well as light-time. See Meeus (33.1) and
+/// the note that follows it.
+fn geometry_with_light_time(planet: Planet, days: f64, light_time: f64) -> Geometry {
+    let earth = heliocentric_position_at(&vsop87::EARTH, days - light_time);
+    let heliocentric = heliocentric_position_at(planet.series(), days - light_time);
+
+    let rectangular = |position: &HeliocentricPosition| {
+        (
+            position.distance * cos_deg(position.latitude) * cos_deg(position.longitude),
+            position.distance * cos_deg(position.latitude) * sin_deg(position.longitude),
+            position.distance * sin_deg(position.latitude),
+        )
+    };
+    let (x0, y0, z0) = rectangular(&earth);
+    let (x1, y1, z1) = rectangular(&heliocentric);
+    let (x, y, z) = (x1 - x0, y1 - y0, z1 - z0);
+
+    // The small conversion from the VSOP87 frame to FK5 (under 0.1") is left
+    // out
+    Geometry {
+        position: EclipticPosition {
+            longitude: normalize_degrees(atan2_deg(y, x) + nutation_in_longitude(days)),
+            latitude: atan2_deg(z, libm::sqrt(x * x + y * y)),
+            distance: libm::sqrt(x * x + y * y + z * z) * AU_KM,
+        },
+        heliocentric,
+    }
+}
+
+fn heliocentric_position_at(series: &vsop87::Series, days: f64) -> HeliocentricPosition {
+    let t = ephemeris_days(days) / 365_250.0;
+    HeliocentricPosition {
+        longitude: normalize_degrees(evaluate(series.longitude, t).to_degrees()),
+        latitude: evaluate(series.latitude, t).to_degrees(),
+        distance: evaluate(series.radius, t),
+    }
+}
+
+/// Sums a coordinate's series at `t` millennia from J2000.0.
+fn evaluate(tables: &[&[vsop87::Term]], t: f64) -> f64 {
+    let mut sum = 0.0;
+    for table in tables.iter().rev() {
+        let mut value = 0.0;
+        for &(amplitude, phase, frequency) in table.iter() {
+            value += amplitude * libm::cos(phase + frequency * t);
+        }
+        sum = sum * t + value;
+    }
+    sum * 1e-8
+}
+
+fn magnitude_at(planet: Planet, days: f64) -> f64 {
+    let geometry = geometry_at(planet, days);
+    let sun_distance = geometry.heliocentric.distance;
+    let earth_distance = geometry.position.distance / AU_KM;
+    let earth_sun_distance = sun::ecliptic_position_at(days).distance / AU_KM;
+
+    // Phase angle, the angle between the sun and earth as seen from the planet
+    let i = acos_deg(
+        ((sun_distance * sun_distance + earth_distance * earth_distance
+            - earth_sun_distance * earth_sun_distance)
+            / (2.0 * sun_distance * earth_distance))
+            .clamp(-1.0, 1.0),
+    );
+    let distance_term = 5.0 * libm::log10(sun_distance * earth_distance);
+
+    match planet {
+        Planet::Mercury => {
+            -0.42 + distance_term + 0.0380 * i - 0.000273 * i * i + 0.000002 * i * i * i
+        }
+        Planet::Venus => {
+            -4.40 + distance_term + 0.0009 * i + 0.000239 * i * i - 0.00000065 * i * i * i
+        }
+        Planet::Mars => -1.52 + distance_term + 0.016 * i,
+        Planet::Jupiter => -9.40 + distance_term + 0.005 * i,
+        Planet::Saturn => {
+            let (ring_tilt, longitude_difference) = saturn_ring_geometry(&geometry, days);
+            let sin_tilt = sin_deg(ring_tilt.abs());
+            -8.88 + distance_term + 0.044 * longitude_difference.abs() - 2.60 * sin_tilt
+                + 1.25 * sin_tilt * sin_tilt
+        }
+    }
+}
+
+/// Gets the tilt of Saturn's rings towards the earth, B, and the difference
+/// in longitude between the sun and earth as seen from Saturn, measured in
+/// the plane of the rings, ΔU. Both are in degrees.
+///
+/// See Meeus, "Astronomical Algorithms", ch. 45.
+fn saturn_ring_geometry(geometry: &Geometry, days: f64) -> (f64, f64) {
+    let t = ephemeris_days(days) / 36525.0;
+    // Inclination and ascending node of the plane of the rings
+    let inclination = 28.075216 - 0.012998 * t + 0.000004 * t * t;
+    let node = 169.508470 + 1.394681 * t + 0.000412 * t * t;
+
+    let position = &geometry.position;
+    let ring_tilt = asin_deg(
+        sin_deg(inclination) * cos_deg(position.latitude) * sin_deg(position.longitude - node)
+            - cos_deg(inclination) * sin_deg(position.latitude),
+    );
+
+    // The sun's direction as seen from Saturn, corrected for aberration
+    let heliocentric = &geometry.heliocentric;
+    let longitude = heliocentric.longitude - 0.01759 / heliocentric.distance;
+    let latitude = heliocentric.latitude
+        - 0.000764 * cos_deg(heliocentric.longitude - node) / heliocentric.distance;
+
+    let ring_longitude = |longitude: f64, latitude: f64| {
+        atan2_deg(
+            sin_deg(inclination) * sin_deg(latitude)
+                + cos_deg(inclination) * cos_deg(latitude) * sin_deg(longitude - node),
+            cos_deg(latitude) * cos_deg(longitude - node),
+        )
+    };
+    let longitude_difference = normalize_degrees_signed(
+        ring_longitude(longitude, latitude) - ring_longitude(position.longitude, position.latitude),
+    );
+
+    (ring_tilt, longitude_difference)
+}
@@ -0,0 +1,748 @@
+use core::f64::consts::PI;
+
+/// A periodic term (A, B, C), evaluated as A cos(B + C t) where t is in Julian
+/// millennia from J2000.0 (TT). A is in units of 1e-8 radians, or AU for the
+/// radius vector, B is in radians and C in radians per millennium.
+pub(super) type Term = (f64, f64, f64);
+
+/// The VSOP87D series for a planet's heliocentric longitude, latitude and
+/// radius vector, referred to the ecliptic and equinox of date. The nth table
+/// of each coordinate is multiplied by t^n.
+///
+/// These start from Meeus, "Astronomical Algorithms", appendix III, dropping
+/// terms that stay below 3e-6 over a century either side of J2000.0. That
+/// costs about 10" against the full series.
+pub(super) struct Series {
+    pub longitude: &'static [&'static [Term]],
+    pub latitude: &'static [&'static [Term]],
+    pub radius: &'static [&'static [Term]],
+}
+
+pub(super) const MERCURY: Series = Series {
+    longitude: &[&MERCURY_L0, &MERCURY_L1, &MERCURY_L2],
+    latitude: &[&MERCURY_B0, &MERCURY_B1],
+    radius: &[&MERCURY_R0, &MERCURY_R1],
+};
+
+pub(super) const VENUS: Series = Series {
+    longitude: &[&VENUS_L0, &VENUS_L1, &VENUS_L2],
+    latitude: &[&VENUS_B0, &VENUS_B1],
+    radius: &[&VENUS_R0, &VENUS_R1],
+};
+
+pub(super) const EARTH: Series = Series {
+    longitude: &[&EARTH_L0, &EARTH_L1, &EARTH_L2],
+    latitude: &[],
+    radius: &[&EARTH_R0, &EARTH_R1],
+};
+
+pub(super) const MARS: Series = Series {
+    longitude: &[&MARS_L0, &MARS_L1, &MARS_L2],
+    latitude: &[&MARS_B0, &MARS_B1],
+    radius: &[&MARS_R0, &MARS_R1, &MARS_R2],
+};
+
+pub(super) const JUPITER: Series = Series {
+    longitude: &[&JUPITER_L0, &JUPITER_L1, &JUPITER_L2],
+    latitude: &[&JUPITER_B0, &JUPITER_B1],
+    radius: &[&JUPITER_R0, &JUPITER_R1, &JUPITER_R2],
+};
+
+pub(super) const SATURN: Series = Series {
+    longitude: &[&SATURN_L0, &SATURN_L1, &SATURN_L2],
+    latitude: &[&SATURN_B0, &SATURN_B1],
+    radius: &[&SATURN_R0, &SATURN_R1, &SATURN_R2],
+};
+
+#[rustfmt::skip]
+const MERCURY_L0: [Term; 22] = [
+    (440250710.0, 0.0, 0.0),
+    (40989415.0, 1.48302034, 26087.90314157),
+    (5046294.0, 4.4778549, 52175.8062831),
+    (855347.0, 1.165203, 78263.709425),
+    (165590.0, 4.119692, 104351.612566),
+    (34562.0, 0.77931, 130439.51571),
+    (7583.0, 3.7135, 156527.4188),
+    (3560.0, 1.5120, 1109.3786),
+    (1803.0, 4.1033, 5661.3320),
+    (1726.0, 0.3583, 182615.3220),
+    (1590.0, 2.9951, 25028.5212),
+    (1365.0, 4.5992, 27197.2817),
+    (1017.0, 0.8803, 31749.2352),
+    (714.0, 1.541, 24978.525),
+    (644.0, 5.303, 21535.950),
+    (451.0, 6.050, 51116.424),
+    (404.0, 3.282, 208703.225),
+    (352.0, 5.242, 20426.571),
+    (345.0, 2.792, 15874.618),
+    (343.0, 5.765, 955.600),
+    (339.0, 5.863, 25558.212),
+    (325.0, 1.337, 53285.185),
+];
+
+#[rustfmt::skip]
+const MERCURY_L1: [Term; 6] = [
+    (2608814706223.0, 0.0, 0.0),
+    (1126008.0, 6.2170397, 26087.9031416),
+    (303471.0, 3.055655, 52175.806283),
+    (80538.0, 6.10455, 78263.70942),
+    (21245.0, 2.83532, 104351.61257),
+    (5592.0, 5.8268, 130439.5157),
+];
+
+#[rustfmt::skip]
+const MERCURY_L2: [Term; 1] = [
+    (53050.0, 0.0, 0.0),
+];
+
+#[rustfmt::skip]
+const MERCURY_B0: [Term; 9] = [
+    (11737529.0, 1.98357499, 26087.90314157),
+    (2388077.0, 5.0373896, 52175.8062831),
+    (1222840.0, PI, 0.0),
+    (543252.0, 1.796444, 78263.709425),
+    (129779.0, 4.832325, 104351.612566),
+    (31867.0, 1.58088, 130439.51571),
+    (7963.0, 4.6097, 156527.4188),
+    (2014.0, 1.3532, 182615.3220),
+    (514.0, 4.378, 208703.225),
+];
+
+#[rustfmt::skip]
+const MERCURY_B1: [Term; 5] = [
+    (429151.0, 3.501698, 26087.903142),
+    (146234.0, PI, 0.0),
+    (22675.0, 0.01515, 52175.80628),
+    (10895.0, 0.48540, 78263.70942),
+    (6353.0, 3.4294, 104351.6126),
+];
+
+#[rustfmt::skip]
+const MERCURY_R0: [Term; 7] = [
+    (39528272.0, 0.0, 0.0),
+    (7834132.0, 6.1923372, 26087.9031416),
+    (795526.0, 2.959897, 52175.806283),
+    (121282.0, 6.010642, 78263.709425),
+    (21922.0, 2.77820, 104351.61257),
+    (4354.0, 5.8289, 130439.5157),
+    (918.0, 2.597, 156527.419),
+];
+
+#[rustfmt::skip]
+const MERCURY_R1: [Term; 3] = [
+    (217348.0, 4.656172, 26087.903142),
+    (44142.0, 1.42386, 52175.80628),
+    (10094.0, 4.47466, 78263.70942),
+];
+
+#[rustfmt::skip]
+const VENUS_L0: [Term; 18] = [
+    (317614667.0, 0.0, 0.0),
+    (1353968.0, 5.5931332, 10213.2855462),
+    (89892.0, 5.30650, 20426.57109),
+    (5477.0, 4.4163, 7860.4194),
+    (3456.0, 2.6996, 11790.6291),
+    (2372.0, 2.9938, 3930.2097),
+    (1664.0, 4.2502, 1577.3435),
+    (1438.0, 4.1575, 9683.5946),
+    (1317.0, 5.1867, 26.2983),
+    (1201.0, 6.1536, 30639.8566),
+    (769.0, 0.816, 9437.763),
+    (761.0, 1.950, 529.691),
+    (708.0, 1.065, 775.523),
+    (585.0, 3.998, 191.448),
+    (500.0, 4.123, 15720.839),
+    (429.0, 3.586, 19367.189),
+    (327.0, 5.677, 5507.553),
+    (326.0, 4.591, 10404.734),
+];
+
+#[rustfmt::skip]
+const VENUS_L1: [Term; 3] = [
+    (1021352943053.0, 0.0, 0.0),
+    (95708.0, 2.46424, 10213.28555),
+    (14445.0, 0.51625, 20426.57109),
+];
+
+#[rustfmt::skip]
+const VENUS_L2: [Term; 1] = [
+    (54127.0, 0.0, 0.0),
+];
+
+#[rustfmt::skip]
+const VENUS_B0: [Term; 4] = [
+    (5923638.0, 0.2670278, 10213.2855462),
+    (40108.0, 1.14737, 20426.57109),
+    (32815.0, PI, 0.0),
+    (1011.0, 1.0895, 30639.8566),
+];
+
+#[rustfmt::skip]
+const VENUS_B1: [Term; 2] = [
+    (513348.0, 1.803643, 10213.285546),
+    (4380.0, 3.3862, 20426.5711),
+];
+
+#[rustfmt::skip]
+const VENUS_R0: [Term; 7] = [
+    (72334821.0, 0.0, 0.0),
+    (489824.0, 4.021518, 10213.285546),
+    (1658.0, 4.9021, 20426.5711),
+    (1632.0, 2.8455, 7860.4194),
+    (1378.0, 1.1285, 11790.6291),
+    (498.0, 2.587, 9683.595),
+    (374.0, 1.423, 3930.210),
+];
+
+#[rustfmt::skip]
+const VENUS_R1: [Term; 1] = [
+    (34551.0, 0.89199, 10213.28555),
+];
+
+#[rustfmt::skip]
+const EARTH_L0: [Term; 20] = [
+    (175347046.0, 0.0, 0.0),
+    (3341656.0, 4.6692568, 6283.0758500),
+    (34894.0, 4.62610, 12566.15170),
+    (3497.0, 2.7441, 5753.3849),
+    (3418.0, 2.8289, 3.5231),
+    (3136.0, 3.6277, 77713.7715),
+    (2676.0, 4.4181, 7860.4194),
+    (2343.0, 6.1352, 3930.2097),
+    (1324.0, 0.7425, 11506.7698),
+    (1273.0, 2.0371, 529.6910),
+    (1199.0, 1.1096, 1577.3435),
+    (990.0, 5.233, 5884.927),
+    (902.0, 2.045, 26.298),
+    (857.0, 3.508, 398.149),
+    (780.0, 1.179, 5223.694),
+    (753.0, 2.533, 5507.553),
+    (505.0, 4.583, 18849.228),
+    (492.0, 4.205, 775.523),
+    (357.0, 2.920, 0.067),
+    (317.0, 5.849, 11790.629),
+];
+
+#[rustfmt::skip]
+const EARTH_L1: [Term; 3] = [
+    (628331966747.0, 0.0, 0.0),
+    (206059.0, 2.678235, 6283.075850),
+    (4303.0, 2.6351, 12566.1517),
+];
+
+#[rustfmt::skip]
+const EARTH_L2: [Term; 1] = [
+    (52919.0, 0.0, 0.0),
+];
+
+#[rustfmt::skip]
+const EARTH_R0: [Term; 12] = [
+    (100013989.0, 0.0, 0.0),
+    (1670700.0, 3.0984635, 6283.0758500),
+    (13956.0, 3.05525, 12566.15170),
+    (3084.0, 5.1985, 77713.7715),
+    (1628.0, 1.1739, 5753.3849),
+    (1576.0, 2.8469, 7860.4194),
+    (925.0, 5.453, 11506.770),
+    (542.0, 4.564, 3930.210),
+    (472.0, 3.661, 5884.927),
+    (346.0, 0.964, 5507.553),
+    (329.0, 5.900, 5223.694),
+    (307.0, 0.299, 5573.143),
+];
+
+#[rustfmt::skip]
+const EARTH_R1: [Term; 1] = [
+    (103019.0, 1.107490, 6283.075850),
+];
+
+#[rustfmt::skip]
+const MARS_L0: [Term; 41] = [
+    (620347712.0, 0.0, 0.0),
+    (18656368.0, 5.05037100, 3340.61242670),
+    (1108217.0, 5.4009984, 6681.2248534),
+    (91798.0, 5.75479, 10021.83728),
+    (27745.0, 5.97050, 3.52312),
+    (12316.0, 0.84956, 2810.92146),
+    (10610.0, 2.93959, 2281.23050),
+    (8927.0, 4.1570, 0.0173),
+    (8716.0, 6.1101, 13362.4497),
+    (7775.0, 3.3397, 5621.8429),
+    (6798.0, 0.3646, 398.1490),
+    (4161.0, 0.2281, 2942.4634),
+    (3575.0, 1.6619, 2544.3144),
+    (3075.0, 0.8570, 191.4483),
+    (2938.0, 6.0789, 0.0673),
+    (2628.0, 0.6481, 3337.0893),
+    (2580.0, 0.0300, 3344.1355),
+    (2389.0, 5.0390, 796.2980),
+    (1799.0, 0.6563, 529.6910),
+    (1546.0, 2.9158, 1751.5395),
+    (1528.0, 1.1498, 6151.5339),
+    (1286.0, 3.0680, 2146.1654),
+    (1264.0, 3.6228, 5092.1520),
+    (1025.0, 3.6933, 8962.4553),
+    (892.0, 0.183, 16703.062),
+    (859.0, 2.401, 2914.014),
+    (833.0, 4.495, 3340.630),
+    (833.0, 2.464, 3340.595),
+    (749.0, 3.822, 155.420),
+    (724.0, 0.675, 3738.761),
+    (713.0, 3.663, 1059.382),
+    (655.0, 0.489, 3127.313),
+    (636.0, 2.922, 8432.764),
+    (553.0, 4.475, 1748.016),
+    (550.0, 3.810, 0.980),
+    (472.0, 3.625, 1194.447),
+    (426.0, 0.554, 6283.076),
+    (415.0, 0.497, 213.299),
+    (312.0, 0.999, 6677.702),
+    (307.0, 0.381, 6684.748),
+    (302.0, 4.486, 3532.061),
+];
+
+#[rustfmt::skip]
+const MARS_L1: [Term; 5] = [
+    (334085627474.0, 0.0, 0.0),
+    (1458227.0, 3.6042605, 3340.6124267),
+    (164901.0, 3.926313, 6681.224853),
+    (19963.0, 4.26594, 10021.83728),
+    (3452.0, 4.7321, 3.5231),
+];
+
+#[rustfmt::skip]
+const MARS_L2: [Term; 2] = [
+    (58016.0, 2.04979, 3340.61243),
+    (54188.0, 0.0, 0.0),
+];
+
+#[rustfmt::skip]
+const MARS_B0: [Term; 8] = [
+    (3197135.0, 3.7683204, 3340.6124267),
+    (298033.0, 4.106170, 6681.224853),
+    (289105.0, 0.0, 0.0),
+    (31366.0, 4.44651, 10021.83728),
+    (3484.0, 4.7881, 13362.4497),
+    (443.0, 5.026, 3344.136),
+    (443.0, 5.652, 3337.089),
+    (399.0, 5.131, 16703.062),
+];
+
+#[rustfmt::skip]
+const MARS_B1: [Term; 3] = [
+    (350069.0, 5.368478, 3340.612427),
+    (14116.0, PI, 0.0),
+    (9671.0, 5.4788, 6681.2249),
+];
+
+#[rustfmt::skip]
+const MARS_R0: [Term; 28] = [
+    (153033488.0, 0.0, 0.0),
+    (14184953.0, 3.47971284, 3340.61242670),
+    (660776.0, 3.817834, 6681.224853),
+    (46179.0, 4.15595, 10021.83728),
+    (8110.0, 5.5596, 2810.9215),
+    (7485.0, 1.7724, 5621.8429),
+    (5523.0, 1.3644, 2281.2305),
+    (3825.0, 4.4941, 13362.4497),
+    (2484.0, 4.9255, 2942.4634),
+    (2307.0, 0.0908, 2544.3144),
+    (1999.0, 5.3606, 3337.0893),
+    (1960.0, 4.7425, 3344.1355),
+    (1167.0, 2.1126, 5092.1520),
+    (1103.0, 5.0091, 398.1490),
+    (992.0, 5.839, 6151.534),
+    (899.0, 4.408, 529.691),
+    (807.0, 2.102, 1059.382),
+    (798.0, 3.448, 796.298),
+    (741.0, 1.499, 2146.165),
+    (726.0, 1.245, 8432.764),
+    (692.0, 2.134, 8962.455),
+    (633.0, 0.894, 3340.595),
+    (633.0, 2.924, 3340.630),
+    (630.0, 1.287, 1751.540),
+    (574.0, 0.829, 2914.014),
+    (526.0, 5.383, 3738.761),
+    (473.0, 5.199, 3127.313),
+    (348.0, 4.832, 16703.062),
+];
+
+#[rustfmt::skip]
+const MARS_R1: [Term; 4] = [
+    (1107433.0, 2.0325052, 3340.6124267),
+    (103176.0, 2.370718, 6681.224853),
+    (12877.0, 0.0, 0.0),
+    (10816.0, 2.70888, 10021.83728),
+];
+
+#[rustfmt::skip]
+const MARS_R2: [Term; 1] = [
+    (44242.0, 0.47931, 3340.61243),
+];
+
+#[rustfmt::skip]
+const JUPITER_L0: [Term; 43] = [
+    (59954691.0, 0.0, 0.0),
+    (9695899.0, 5.0619179, 529.6909651),
+    (573610.0, 1.444062, 7.113547),
+    (306389.0, 5.417347, 1059.381930),
+    (97178.0, 4.14265, 632.78374),
+    (72903.0, 3.64043, 522.57742),
+    (64264.0, 3.41145, 103.09277),
+    (39806.0, 2.29377, 419.48464),
+    (38858.0, 1.27232, 316.39187),
+    (27965.0, 1.78455, 536.80451),
+    (13590.0, 5.77481, 1589.07290),
+    (8769.0, 3.6300, 949.1756),
+    (8246.0, 3.5823, 206.1855),
+    (7368.0, 5.0810, 735.8765),
+    (6263.0, 0.0250, 213.2991),
+    (6114.0, 4.5132, 1162.4747),
+    (5305.0, 4.1863, 1052.2684),
+    (5305.0, 1.3067, 14.2271),
+    (4905.0, 1.3208, 110.2063),
+    (4647.0, 4.6996, 3.9322),
+    (3045.0, 4.3168, 426.5982),
+    (2610.0, 1.5667, 846.0828),
+    (2028.0, 1.0638, 3.1814),
+    (1921.0, 0.9717, 639.8973),
+    (1765.0, 2.1415, 1066.4955),
+    (1723.0, 3.8804, 1265.5675),
+    (1633.0, 3.5820, 515.4639),
+    (1432.0, 4.2968, 625.6702),
+    (973.0, 4.098, 95.979),
+    (884.0, 2.437, 412.371),
+    (733.0, 6.085, 838.969),
+    (731.0, 3.806, 1581.959),
+    (709.0, 1.293, 742.990),
+    (692.0, 6.134, 2118.764),
+    (614.0, 4.109, 1478.867),
+    (582.0, 4.540, 309.278),
+    (495.0, 3.756, 323.505),
+    (441.0, 2.958, 454.909),
+    (417.0, 1.036, 2.448),
+    (390.0, 4.897, 1692.166),
+    (376.0, 4.703, 1368.660),
+    (341.0, 5.715, 533.623),
+    (330.0, 4.740, 0.048),
+];
+
+#[rustfmt::skip]
+const JUPITER_L1: [Term; 9] = [
+    (52993480757.0, 0.0, 0.0),
+    (489741.0, 4.220667, 529.690965),
+    (228919.0, 6.026475, 7.113547),
+    (27655.0, 4.57266, 1059.38193),
+    (20721.0, 5.45939, 522.57742),
+    (12106.0, 0.16986, 536.80451),
+    (6068.0, 4.4242, 103.0928),
+    (5434.0, 3.9848, 419.4846),
+    (4238.0, 5.8901, 14.2271),
+];
+
+#[rustfmt::skip]
+const JUPITER_L2: [Term; 3] = [
+    (47234.0, 4.32148, 7.11355),
+    (38966.0, 0.0, 0.0),
+    (30629.0, 2.93021, 529.69097),
+];
+
+#[rustfmt::skip]
+const JUPITER_B0: [Term; 19] = [
+    (2268616.0, 3.5585261, 529.6909651),
+    (110090.0, 0.0, 0.0),
+    (109972.0, 3.908093, 1059.381930),
+    (8101.0, 3.6051, 522.5774),
+    (6438.0, 0.3063, 536.8045),
+    (6044.0, 4.2588, 1589.0729),
+    (1107.0, 2.9853, 1162.4747),
+    (944.0, 1.675, 426.598),
+    (942.0, 2.936, 1052.268),
+    (894.0, 1.754, 7.114),
+    (836.0, 5.179, 103.093),
+    (767.0, 2.155, 632.784),
+    (684.0, 3.678, 213.299),
+    (629.0, 0.643, 1066.495),
+    (559.0, 0.014, 846.083),
+    (532.0, 2.703, 110.206),
+    (464.0, 1.173, 949.176),
+    (431.0, 2.608, 419.485),
+    (351.0, 4.611, 2118.764),
+];
+
+#[rustfmt::skip]
+const JUPITER_B1: [Term; 3] = [
+    (177352.0, 5.701665, 529.690965),
+    (3230.0, 5.7794, 1059.3819),
+    (3081.0, 5.4746, 522.5774),
+];
+
+#[rustfmt::skip]
+const JUPITER_R0: [Term; 46] = [
+    (520887429.0, 0.0, 0.0),
+    (25209327.0, 3.49108640, 529.69096509),
+    (610600.0, 3.841154, 1059.381930),
+    (282029.0, 2.574199, 632.783739),
+    (187647.0, 2.075904, 522.577418),
+    (86793.0, 0.71001, 419.48464),
+    (72063.0, 0.21466, 536.80451),
+    (65517.0, 5.97996, 316.39187),
+    (30135.0, 2.16132, 949.17561),
+    (29135.0, 1.67759, 103.09277),
+    (23947.0, 0.27458, 7.11355),
+    (23453.0, 3.54023, 735.87651),
+    (22284.0, 4.19363, 1589.07290),
+    (13033.0, 2.96043, 1162.47470),
+    (12749.0, 2.71550, 1052.26838),
+    (9703.0, 1.9067, 206.1855),
+    (9161.0, 4.4135, 213.2991),
+    (7895.0, 2.4791, 426.5982),
+    (7058.0, 2.1818, 1265.5675),
+    (6138.0, 6.2642, 846.0828),
+    (5477.0, 5.6573, 639.8973),
+    (4170.0, 2.0161, 515.4639),
+    (4137.0, 2.7222, 625.6702),
+    (3503.0, 0.5653, 1066.4955),
+    (2617.0, 2.0099, 1581.9593),
+    (2500.0, 4.5518, 838.9693),
+    (2128.0, 6.1275, 742.9901),
+    (1912.0, 0.8562, 412.3711),
+    (1611.0, 3.0887, 1368.6603),
+    (1479.0, 2.6803, 1478.8666),
+    (1231.0, 1.8904, 323.5054),
+    (1217.0, 1.8017, 110.2063),
+    (1015.0, 1.3867, 454.9094),
+    (999.0, 2.872, 309.278),
+    (961.0, 4.549, 2118.764),
+    (886.0, 4.148, 533.623),
+    (821.0, 1.593, 1898.351),
+    (812.0, 5.941, 909.819),
+    (777.0, 3.677, 728.763),
+    (727.0, 3.988, 1155.361),
+    (655.0, 2.791, 1685.052),
+    (654.0, 3.382, 1692.166),
+    (621.0, 4.823, 956.289),
+    (615.0, 2.276, 942.062),
+    (562.0, 0.081, 543.918),
+    (542.0, 0.284, 525.759),
+];
+
+#[rustfmt::skip]
+const JUPITER_R1: [Term; 10] = [
+    (1271802.0, 2.6493751, 529.6909651),
+    (61662.0, 3.00076, 1059.38193),
+    (53444.0, 3.89718, 522.57742),
+    (41390.0, 0.0, 0.0),
+    (31185.0, 4.88277, 536.80451),
+    (11847.0, 2.41330, 419.48464),
+    (9166.0, 4.7598, 7.1135),
+    (3404.0, 3.3469, 1589.0729),
+    (3203.0, 5.2108, 735.8765),
+    (3176.0, 2.7930, 103.0928),
+];
+
+#[rustfmt::skip]
+const JUPITER_R2: [Term; 1] = [
+    (79645.0, 1.35866, 529.69097),
+];
+
+#[rustfmt::skip]
+const SATURN_L0: [Term; 59] = [
+    (87401354.0, 0.0, 0.0),
+    (11107660.0, 3.96205090, 213.29909544),
+    (1414151.0, 4.5858152, 7.1135470),
+    (398379.0, 0.521120, 206.185548),
+    (350769.0, 3.303299, 426.598191),
+    (206816.0, 0.246584, 103.092774),
+    (79271.0, 3.84007, 220.41264),
+    (23990.0, 4.66977, 110.20632),
+    (16574.0, 0.43719, 419.48464),
+    (15820.0, 0.93809, 632.78374),
+    (15054.0, 2.71670, 639.89729),
+    (14907.0, 5.76903, 316.39187),
+    (14610.0, 1.56519, 3.93215),
+    (13160.0, 4.44891, 14.22709),
+    (13005.0, 5.98119, 11.04570),
+    (10725.0, 3.12940, 202.25340),
+    (6126.0, 1.7633, 277.0350),
+    (5863.0, 0.2366, 529.6910),
+    (5228.0, 4.2078, 3.1814),
+    (5020.0, 3.1779, 433.7117),
+    (4593.0, 0.6198, 199.0720),
+    (4006.0, 2.2448, 63.7359),
+    (3874.0, 3.2228, 138.5175),
+    (3269.0, 0.7749, 949.1756),
+    (2954.0, 0.9828, 95.9792),
+    (2461.0, 2.0316, 735.8765),
+    (1758.0, 3.2658, 522.5774),
+    (1640.0, 5.5050, 846.0828),
+    (1581.0, 4.3727, 309.2783),
+    (1391.0, 4.0233, 323.5054),
+    (1124.0, 2.8373, 415.5525),
+    (1087.0, 4.1834, 2.4477),
+    (1017.0, 3.7170, 227.5262),
+    (957.0, 0.507, 1265.567),
+    (853.0, 3.421, 175.166),
+    (849.0, 3.191, 209.367),
+    (789.0, 5.007, 0.963),
+    (749.0, 2.144, 853.196),
+    (744.0, 5.253, 224.345),
+    (687.0, 1.747, 1052.268),
+    (654.0, 1.599, 0.048),
+    (634.0, 2.299, 412.371),
+    (625.0, 0.970, 210.118),
+    (580.0, 3.093, 234.640),
+    (546.0, 2.127, 330.619),
+    (543.0, 1.518, 9.561),
+    (530.0, 4.452, 117.320),
+    (478.0, 2.965, 137.033),
+    (474.0, 5.475, 742.990),
+    (452.0, 1.044, 490.334),
+    (449.0, 1.290, 127.472),
+    (372.0, 2.278, 217.231),
+    (355.0, 3.013, 838.969),
+    (347.0, 1.539, 206.234),
+    (343.0, 0.246, 0.521),
+    (330.0, 0.247, 1581.959),
+    (322.0, 0.961, 203.738),
+    (322.0, 2.572, 647.011),
+    (309.0, 3.495, 216.480),
+];
+
+#[rustfmt::skip]
+const SATURN_L1: [Term; 15] = [
+    (21354295596.0, 0.0, 0.0),
+    (1296855.0, 1.8282054, 213.2990954),
+    (564348.0, 2.885001, 7.113547),
+    (107679.0, 2.277699, 206.185548),
+    (98323.0, 1.08070, 426.59819),
+    (40255.0, 2.04128, 220.41264),
+    (19942.0, 1.27955, 103.09277),
+    (10512.0, 2.74880, 14.22709),
+    (6939.0, 0.4049, 639.8973),
+    (4803.0, 2.4419, 419.4846),
+    (4056.0, 2.9217, 110.2063),
+    (3769.0, 3.6497, 3.9322),
+    (3385.0, 2.4169, 3.1814),
+    (3302.0, 1.2626, 433.7117),
+    (3071.0, 2.3274, 199.0720),
+];
+
+#[rustfmt::skip]
+const SATURN_L2: [Term; 3] = [
+    (116441.0, 1.179879, 7.113547),
+    (91921.0, 0.07425, 213.29910),
+    (90592.0, 0.0, 0.0),
+];
+
+#[rustfmt::skip]
+const SATURN_B0: [Term; 21] = [
+    (4330678.0, 3.6028443, 213.2990954),
+    (240348.0, 2.852385, 426.598191),
+    (84746.0, 0.0, 0.0),
+    (34116.0, 0.57297, 206.18555),
+    (30863.0, 3.48442, 220.41264),
+    (14734.0, 2.11847, 639.89729),
+    (9917.0, 5.7900, 419.4846),
+    (6994.0, 4.7360, 7.1135),
+    (4808.0, 5.4331, 316.3919),
+    (4788.0, 4.9651, 110.2063),
+    (3432.0, 2.7326, 433.7117),
+    (1506.0, 6.0130, 103.0928),
+    (1060.0, 5.6310, 529.6910),
+    (969.0, 5.204, 632.784),
+    (942.0, 1.396, 853.196),
+    (708.0, 3.803, 323.505),
+    (552.0, 5.131, 202.253),
+    (400.0, 3.359, 227.526),
+    (319.0, 3.626, 209.367),
+    (316.0, 1.997, 647.011),
+    (314.0, 0.465, 217.231),
+];
+
+#[rustfmt::skip]
+const SATURN_B1: [Term; 6] = [
+    (397555.0, 5.332900, 213.299095),
+    (49479.0, PI, 0.0),
+    (18572.0, 6.09919, 426.59819),
+    (14801.0, 2.30586, 206.18555),
+    (9644.0, 1.6967, 220.4126),
+    (3757.0, 1.2543, 419.4846),
+];
+
+#[rustfmt::skip]
+const SATURN_R0: [Term; 44] = [
+    (955758136.0, 0.0, 0.0),
+    (52921382.0, 2.39226220, 213.29909544),
+    (1873680.0, 5.2354961, 206.1855484),
+    (1464664.0, 1.6476305, 426.5981909),
+    (821891.0, 5.935200, 316.391870),
+    (547507.0, 5.015326, 103.092774),
+    (371684.0, 2.271148, 220.412642),
+    (361778.0, 3.139043, 7.113547),
+    (140618.0, 5.704067, 632.783739),
+    (108975.0, 3.293136, 110.206321),
+    (69007.0, 5.94100, 419.48464),
+    (61053.0, 0.94038, 639.89729),
+    (48913.0, 1.55733, 202.25340),
+    (34144.0, 0.19519, 277.03499),
+    (32402.0, 5.47085, 949.17561),
+    (20937.0, 0.46349, 735.87651),
+    (20839.0, 1.52103, 433.71174),
+    (20747.0, 5.33256, 199.07200),
+    (15298.0, 3.05944, 529.69097),
+    (14296.0, 2.60434, 323.50542),
+    (12884.0, 1.64892, 138.51750),
+    (11993.0, 5.98051, 846.08283),
+    (11380.0, 1.73106, 522.57742),
+    (9796.0, 5.2048, 1265.5675),
+    (7753.0, 5.8519, 95.9792),
+    (6771.0, 3.0043, 14.2271),
+    (6466.0, 0.1773, 1052.2684),
+    (5850.0, 1.4552, 415.5525),
+    (5307.0, 0.5974, 63.7359),
+    (4696.0, 2.1492, 227.5262),
+    (4044.0, 1.6401, 209.3669),
+    (3688.0, 0.7802, 412.3711),
+    (3461.0, 1.8509, 175.1661),
+    (3420.0, 4.9455, 1581.9593),
+    (3401.0, 0.5539, 350.3321),
+    (3376.0, 3.6953, 224.3448),
+    (2976.0, 5.6847, 210.1177),
+    (2885.0, 1.3876, 838.9693),
+    (2881.0, 0.1796, 853.1964),
+    (2508.0, 3.5385, 742.9901),
+    (2448.0, 6.1841, 1368.6603),
+    (2406.0, 2.9656, 117.3199),
+    (2174.0, 0.0151, 340.7709),
+    (2024.0, 5.0541, 11.0457),
+];
+
+#[rustfmt::skip]
+const SATURN_R1: [Term; 17] = [
+    (6182981.0, 0.2584352, 213.2990954),
+    (506578.0, 0.711147, 206.185548),
+    (341394.0, 5.796358, 426.598191),
+    (188491.0, 0.472157, 220.412642),
+    (186262.0, PI, 0.0),
+    (143891.0, 1.407449, 7.113547),
+    (49621.0, 6.01744, 103.09277),
+    (20928.0, 5.09246, 639.89729),
+    (19953.0, 1.17560, 419.48464),
+    (18840.0, 1.60820, 110.20632),
+    (13877.0, 0.75886, 199.07200),
+    (12893.0, 5.94330, 433.71174),
+    (5397.0, 1.2885, 14.2271),
+    (4869.0, 0.8679, 323.5054),
+    (4247.0, 0.3930, 227.5262),
+    (3252.0, 1.2585, 95.9792),
+    (3081.0, 3.4366, 522.5774),
+];
+
+#[rustfmt::skip]
+const SATURN_R2: [Term; 4] = [
+    (436902.0, 4.786717, 213.299095),
+    (71923.0, 2.50070, 206.18555),
+    (49767.0, 4.97168, 220.41264),
+    (43221.0, 3.86940, 426.59819),
+];
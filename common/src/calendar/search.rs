@@ -0,0 +1,224 @@
+use crate::rtclock;
+
+/// How to search for events in a function of time: how far apart to sample
+/// it to bracket them, and how precisely to then pin them down.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Search {
+    /// Seconds between samples, which must be positive. This needs to be
+    /// short enough that the function can't cross a value twice, or turn
+    /// twice, between samples, or those events will be missed.
+    pub step: i64,
+    /// Events are located to within this many seconds.
+    pub tolerance: i64,
+}
+
+/// Which way a function crosses a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Rising,
+    Falling,
+}
+
+/// An instant at which a function crosses a value, found by
+/// `Search::crossings`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crossing {
+    pub instant: rtclock::Timestamp,
+    pub direction: Direction,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtremumKind {
+    Minimum,
+    Maximum,
+}
+
+/// A local minimum or maximum of a function, found by `Search::extrema`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Extremum {
+    pub kind: ExtremumKind,
+    pub instant: rtclock::Timestamp,
+    /// The function's value at `instant`.
+    pub value: f64,
+}
+
+impl Search {
+    /// Finds the instants from `start` at which `f` crosses `value`, in
+    /// order. The iterator stops at `end`, or is unbounded if that's `None`.
+    pub fn crossings<F: Fn(rtclock::Timestamp) -> f64>(
+        self,
+        f: F,
+        value: f64,
+        start: rtclock::Timestamp,
+        end: Option<rtclock::Timestamp>,
+    ) -> Crossings<F> {
+        debug_assert!(self.step > 0, "search step must be positive");
+        let previous = f(start) - value;
+        Crossings {
+            search: self,
+            f,
+            value,
+            previous: (start, previous),
+            end,
+        }
+    }
+
+    /// Finds the local minima and maxima of `f` from `start`, in order. The
+    /// iterator stops at `end`, or is unbounded if that's `None`.
+    pub fn extrema<F: Fn(rtclock::Timestamp) -> f64>(
+        self,
+        f: F,
+        start: rtclock::Timestamp,
+        end: Option<rtclock::Timestamp>,
+    ) -> Extrema<F> {
+        debug_assert!(self.step > 0, "search step must be positive");
+        let samples = [start, start + self.step].map(|instant| (instant, f(instant)));
+        Extrema {
+            search: self,
+            f,
+            samples,
+            end,
+        }
+    }
+
+    /// Narrows down the instant between `low` and `high` at which `f` crosses
+    /// `value`, by bisection. If it doesn't, this returns whichever end comes
+    /// closer.
+    pub fn refine_crossing(
+        self,
+        f: &impl Fn(rtclock::Timestamp) -> f64,
+        value: f64,
+        mut low: rtclock::Timestamp,
+        mut high: rtclock::Timestamp,
+    ) -> rtclock::Timestamp {
+        let (low_offset, high_offset) = (f(low) - value, f(high) - value);
+        let low_is_below = low_offset < 0.0;
+        if low_is_below == (high_offset < 0.0) {
+            return if libm::fabs(low_offset) < libm::fabs(high_offset) {
+                low
+            } else {
+                high
+            };
+        }
+        while high - low > self.tolerance {
+            let mid = low + (high - low) / 2;
+            if (f(mid) < value) == low_is_below {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low + (high - low) / 2
+    }
+
+    /// Narrows down the instant between `low` and `high` at which `f` is
+    /// smallest, by ternary search. `f` must have a single minimum in the
+    /// interval.
+    pub fn refine_minimum(
+        self,
+        f: &impl Fn(rtclock::Timestamp) -> f64,
+        mut low: rtclock::Timestamp,
+        mut high: rtclock::Timestamp,
+    ) -> rtclock::Timestamp {
+        // Thirds can't be narrowed past a second
+        while high - low > self.tolerance.max(2) {
+            let third = (high - low) / 3;
+            if f(low + third) < f(high - third) {
+                high = high - third;
+            } else {
+                low = low + third;
+            }
+        }
+        low + (high - low) / 2
+    }
+
+    /// As `refine_minimum`, but for the largest value.
+    pub fn refine_maximum(
+        self,
+        f: &impl Fn(rtclock::Timestamp) -> f64,
+        low: rtclock::Timestamp,
+        high: rtclock::Timestamp,
+    ) -> rtclock::Timestamp {
+        self.refine_minimum(&|instant| -f(instant), low, high)
+    }
+}
+
+/// Iterator over the instants a function crosses a value, returned by
+/// `Search::crossings`.
+pub struct Crossings<F> {
+    search: Search,
+    f: F,
+    value: f64,
+    /// The last sample taken, with the function's value relative to `value`.
+    previous: (rtclock::Timestamp, f64),
+    end: Option<rtclock::Timestamp>,
+}
+
+impl<F: Fn(rtclock::Timestamp) -> f64> Iterator for Crossings<F> {
+    type Item = Crossing;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (low, before) = self.previous;
+            let high = match self.end {
+                Some(end) if low >= end => return None,
+                Some(end) => (low + self.search.step).min(end),
+                None => low + self.search.step,
+            };
+            let after = (self.f)(high) - self.value;
+            self.previous = (high, after);
+
+            let direction = match (before < 0.0, after < 0.0) {
+                (true, false) => Direction::Rising,
+                (false, true) => Direction::Falling,
+                _ => continue,
+            };
+            return Some(Crossing {
+                instant: self.search.refine_crossing(&self.f, self.value, low, high),
+                direction,
+            });
+        }
+    }
+}
+
+/// Iterator over the local minima and maxima of a function, returned by
+/// `Search::extrema`.
+pub struct Extrema<F> {
+    search: Search,
+    f: F,
+    /// The last two samples taken, which with the next bracket an extremum
+    /// if the middle one is highest or lowest.
+    samples: [(rtclock::Timestamp, f64); 2],
+    end: Option<rtclock::Timestamp>,
+}
+
+impl<F: Fn(rtclock::Timestamp) -> f64> Iterator for Extrema<F> {
+    type Item = Extremum;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let [(low, before), (mid, at)] = self.samples;
+            let high = mid + self.search.step;
+            if self.end.is_some_and(|end| high > end) {
+                return None;
+            }
+            let after = (self.f)(high);
+            self.samples = [(mid, at), (high, after)];
+
+            let (kind, instant) = if at <= before && at < after {
+                let instant = self.search.refine_minimum(&self.f, low, high);
+                (ExtremumKind::Minimum, instant)
+            } else if at >= before && at > after {
+                let instant = self.search.refine_maximum(&self.f, low, high);
+                (ExtremumKind::Maximum, instant)
+            } else {
+                continue;
+            };
+            return Some(Extremum {
+                kind,
+                instant,
+                value: (self.f)(instant),
+            });
+        }
+    }
+}
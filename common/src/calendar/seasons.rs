@@ -0,0 +1,282 @@
+use crate::calendar::{
+    Observer, cos_deg, days_from_julian_ephemeris_day, days_since_j2000, sin_deg, sun,
+    timestamp_from_days_since_j2000,
+};
+use crate::rtclock;
+
+/// Mean length of the tropical year in days.
+const TROPICAL_YEAR_DAYS: f64 = 365.242_19;
+
+/// Days since J2000.0 of the March equinox of 2000 (Mar 20, 07:35 UTC).
+const MARCH_EQUINOX_2000_DAYS: f64 = 78.816;
+
+/// Number of times estimates of when the sun reaches a longitude are refined
+/// against its updated longitude. Each step gains a few orders of magnitude.
+const ITERATIONS: usize = 3;
+
+/// An equinox or solstice, or one of the cross-quarter days midway between
+/// them. Variants are in order of the sun's ecliptic longitude, starting from
+/// 0° at the March equinox.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeasonMarker {
+    MarchEquinox,
+    MayCrossQuarter,
+    JuneSolstice,
+    AugustCrossQuarter,
+    SeptemberEquinox,
+    NovemberCrossQuarter,
+    DecemberSolstice,
+    FebruaryCrossQuarter,
+}
+
+impl SeasonMarker {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SeasonMarker::MarchEquinox => "Mar equinox",
+            SeasonMarker::MayCrossQuarter => "May cross-quarter",
+            SeasonMarker::JuneSolstice => "Jun solstice",
+            SeasonMarker::AugustCrossQuarter => "Aug cross-quarter",
+            SeasonMarker::SeptemberEquinox => "Sep equinox",
+            SeasonMarker::NovemberCrossQuarter => "Nov cross-quarter",
+            SeasonMarker::DecemberSolstice => "Dec solstice",
+            SeasonMarker::FebruaryCrossQuarter => "Feb cross-quarter",
+        }
+    }
+
+    /// The sun's apparent ecliptic longitude at this marker, in degrees.
+    pub fn solar_longitude(&self) -> f64 {
+        *self as u8 as f64 * 45.0
+    }
+
+    pub fn is_cross_quarter(&self) -> bool {
+        *self as u8 % 2 == 1
+    }
+
+    pub fn is_solstice(&self) -> bool {
+        *self as u8 % 4 == 2
+    }
+
+    fn from_octant(octant: i64) -> Self {
+        match octant.rem_euclid(8) {
+            0 => SeasonMarker::MarchEquinox,
+            1 => SeasonMarker::MayCrossQuarter,
+            2 => SeasonMarker::JuneSolstice,
+            3 => SeasonMarker::AugustCrossQuarter,
+            4 => SeasonMarker::SeptemberEquinox,
+            5 => SeasonMarker::NovemberCrossQuarter,
+            6 => SeasonMarker::DecemberSolstice,
+            _ => SeasonMarker::FebruaryCrossQuarter,
+        }
+    }
+}
+
+/// An instant at which the sun reaches a season marker.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SeasonEvent {
+    pub marker: SeasonMarker,
+    pub instant: rtclock::Timestamp,
+}
+
+/// The astronomical season containing an instant, running from one equinox
+/// or solstice to the next.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Season {
+    pub start: SeasonEvent,
+    pub end: SeasonEvent,
+}
+
+impl Season {
+    /// Fraction [0, 1] of the season that has passed at `instant`.
+    pub fn progress(&self, instant: impl Into<rtclock::Timestamp>) -> f64 {
+        let elapsed = instant.into() - self.start.instant;
+        (elapsed as f64 / (self.end.instant - self.start.instant) as f64).clamp(0.0, 1.0)
+    }
+
+    /// Name of the season, e.g. "summer", which depends on the observer's
+    /// hemisphere.
+    pub fn label(&self, observer: &Observer) -> &'static str {
+        const NORTHERN: [&str; 4] = ["spring", "summer", "autumn", "winter"];
+        let index = self.start.marker as usize / 2;
+        if observer.latitude >= 0.0 {
+            NORTHERN[index]
+        } else {
+            NORTHERN[(index + 2) % 4]
+        }
+    }
+}
+
+/// Iterator over successive season markers, returned by `get_next_events`.
+pub struct NextEvents {
+    after: rtclock::Timestamp,
+    octant: i64,
+}
+
+impl Iterator for NextEvents {
+    type Item = SeasonEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let event = event_at_octant(self.octant);
+            self.octant += 1;
+            if event.instant > self.after {
+                return Some(event);
+            }
+        }
+    }
+}
+
+/// Gets the equinoxes, solstices and cross-quarter days occurring after
+/// `instant`, in order. The iterator is unbounded, so use `take` or `find` to
+/// pick out the ones needed.
+pub fn get_next_events(instant: impl Into<rtclock::Timestamp>) -> NextEvents {
+    let instant = instant.into();
+    NextEvents {
+        after: instant,
+        // Start an octant early, as the true instants vary around the mean
+        octant: mean_octant(instant) - 1,
+    }
+}
+
+/// Gets the season (spring, summer, etc.) containing `instant`.
+pub fn get_season(instant: impl Into<rtclock::Timestamp>) -> Season {
+    let instant = instant.into();
+    // Round down to the quarter, then step back if it hasn't happened yet
+    let mut quarter = mean_octant(instant).div_euclid(2) + 1;
+    while event_at_octant(quarter * 2).instant > instant {
+        quarter -= 1;
+    }
+    Season {
+        start: event_at_octant(quarter * 2),
+        end: event_at_octant(quarter * 2 + 2),
+    }
+}
+
+/// Gets the instant at which the sun reaches `marker` in `year`. Equinoxes
+/// and solstices are accurate to about a minute, and cross-quarter days to a
+/// few minutes.
+///
+/// See Meeus, "Astronomical Algorithms", ch. 27.
+pub fn get_instant(year: i32, marker: SeasonMarker) -> rtclock::Timestamp {
+    // The February cross-quarter day is the last octant of the previous
+    // astronomical year
+    let octant = (year as i64 - 2000) * 8 + marker as i64
+        - if marker == SeasonMarker::FebruaryCrossQuarter {
+            8
+        } else {
+            0
+        };
+    event_at_octant(octant).instant
+}
+
+/// Estimates the number of the octant containing `instant`, counting from
+/// the March equinox of 2000.
+fn mean_octant(instant: rtclock::Timestamp) -> i64 {
+    let years = (days_since_j2000(instant) - MARCH_EQUINOX_2000_DAYS) / TROPICAL_YEAR_DAYS;
+    libm::floor(years * 8.0) as i64
+}
+
+fn event_at_octant(octant: i64) -> SeasonEvent {
+    let marker = SeasonMarker::from_octant(octant);
+    let days = if marker.is_cross_quarter() {
+        cross_quarter_days(octant)
+    } else {
+        let year = 2000 + octant.div_euclid(8) as i32;
+        quarter_days(year, marker)
+    };
+    SeasonEvent {
+        marker,
+        instant: timestamp_from_days_since_j2000(days),
+    }
+}
+
+/// Mean equinox and solstice polynomials for years -1000 to 1000, in
+/// millennia from year 0: March, June, September then December.
+const MEAN_QUARTERS_BEFORE_1000: [[f64; 5]; 4] = [
+    [1721139.29189, 365242.13740, 0.06134, 0.00111, -0.00071],
+    [1721233.25401, 365241.72562, -0.05323, 0.00907, 0.00025],
+    [1721325.70455, 365242.49558, -0.11677, -0.00297, 0.00074],
+    [1721414.39987, 365242.88257, -0.00769, -0.00933, -0.00006],
+];
+
+/// As above for years 1000 to 3000, in millennia from 2000.
+const MEAN_QUARTERS: [[f64; 5]; 4] = [
+    [2451623.80984, 365242.37404, 0.05169, -0.00411, -0.00057],
+    [2451716.56767, 365241.62603, 0.00325, 0.00888, -0.00030],
+    [2451810.21715, 365242.01767, -0.11575, 0.00337, 0.00078],
+    [2451900.05952, 365242.74049, -0.06223, -0.00823, 0.00032],
+];
+
+/// Periodic corrections to the mean equinoxes and solstices: amplitude in
+/// units of 0.00001 days, phase in degrees and rate in degrees per century.
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// Gets the time of an equinox or solstice in `year`, in days since J2000.0.
+fn quarter_days(year: i32, marker: SeasonMarker) -> f64 {
+    let (coefficients, millennia) = if year < 1000 {
+        (&MEAN_QUARTERS_BEFORE_1000, year as f64 / 1000.0)
+    } else {
+        (&MEAN_QUARTERS, (year as f64 - 2000.0) / 1000.0)
+    };
+    let mut mean_jde = 0.0;
+    for &coefficient in coefficients[marker as usize / 2].iter().rev() {
+        mean_jde = mean_jde * millennia + coefficient;
+    }
+
+    let t = (mean_jde - 2451545.0) / 36525.0;
+    let w = 35999.373 * t - 2.47;
+    let longitude_rate = 1.0 + 0.0334 * cos_deg(w) + 0.0007 * cos_deg(2.0 * w);
+    let mut sum = 0.0;
+    for (amplitude, phase, rate) in PERIODIC_TERMS {
+        sum += amplitude * cos_deg(phase + rate * t);
+    }
+
+    days_from_julian_ephemeris_day(mean_jde + 0.00001 * sum / longitude_rate)
+}
+
+/// Finds when the sun reaches the longitude of the cross-quarter day numbered
+/// `octant`, in days since J2000.0, starting midway between the neighbouring
+/// equinox and solstice.
+fn cross_quarter_days(octant: i64) -> f64 {
+    let before = event_at_octant(octant - 1).instant;
+    let after = event_at_octant(octant + 1).instant;
+    let days = (days_since_j2000(before) + days_since_j2000(after)) / 2.0;
+
+    solar_longitude_days(SeasonMarker::from_octant(octant).solar_longitude(), days)
+}
+
+/// Finds when the sun's apparent longitude reaches `longitude` (in degrees),
+/// starting from an estimate in days since J2000.0 that's within a few weeks.
+pub(crate) fn solar_longitude_days(longitude: f64, mut days: f64) -> f64 {
+    for _ in 0..ITERATIONS {
+        let current = sun::ecliptic_position_at(days).longitude;
+        // The sun moves about 1° a day, so this is a Newton step. See Meeus
+        // (27.1).
+        days += 58.0 * sin_deg(longitude - current);
+    }
+    days
+}
@@ -0,0 +1,180 @@
+use crate::calendar::search::Search;
+use crate::calendar::{
+    AU_KM, EclipticPosition, EquatorialPosition, HOUR_ANGLE_RATE, HorizontalPosition, Observer,
+    cos_crossing_hour_angle, cos_deg, days_since_j2000, ephemeris_days, horizon_dip,
+    local_hour_angle, local_midnight_days, normalize_degrees, nutation_in_longitude, sin_deg,
+    timestamp_from_days_since_j2000,
+};
+use crate::rtclock;
+
+/// Altitude of the sun's centre at sunrise and sunset as seen from sea level:
+/// 34' of atmospheric refraction plus 16' for the sun's semi-diameter.
+pub(crate) const SUNRISE_ALTITUDE: f64 = -0.8333;
+
+/// Number of times the solar noon estimate is refined against the sun's
+/// updated position. The sun moves slowly enough that this converges to well
+/// under a second.
+const ITERATIONS: usize = 4;
+
+/// How sunrise and sunset are searched for. Each is bracketed by the half day
+/// before or after noon, so there's no need to sample in between.
+const CROSSING_SEARCH: Search = Search {
+    step: 43_200,
+    tolerance: 1,
+};
+
+/// When the sun crosses a given altitude over the course of a day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Crossing {
+    /// The sun rises above the altitude at `rise` and sets below it at `set`.
+    RiseSet {
+        rise: rtclock::Timestamp,
+        set: rtclock::Timestamp,
+    },
+    /// The sun stays above the altitude all day (e.g. polar day).
+    AlwaysAbove,
+    /// The sun stays below the altitude all day (e.g. polar night).
+    AlwaysBelow,
+}
+
+impl Crossing {
+    pub fn rise(&self) -> Option<rtclock::Timestamp> {
+        match self {
+            Crossing::RiseSet { rise, .. } => Some(*rise),
+            _ => None,
+        }
+    }
+
+    pub fn set(&self) -> Option<rtclock::Timestamp> {
+        match self {
+            Crossing::RiseSet { set, .. } => Some(*set),
+            _ => None,
+        }
+    }
+}
+
+/// The sun's daily events for an observer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SunEvents {
+    /// When the sun transits the observer's meridian.
+    pub solar_noon: rtclock::Timestamp,
+    /// When the sun's upper limb crosses the observer's apparent horizon.
+    pub daylight: Crossing,
+}
+
+impl SunEvents {
+    /// How long the sun is up, in seconds: from sunrise to sunset, or all or
+    /// none of the day if it doesn't rise or set.
+    pub fn day_length(&self) -> i64 {
+        match self.daylight {
+            Crossing::RiseSet { rise, set } => set - rise,
+            Crossing::AlwaysAbove => 86_400,
+            Crossing::AlwaysBelow => 0,
+        }
+    }
+}
+
+/// Gets the sun's apparent position at an instant, accurate to about 0.01°.
+///
+/// See Meeus, "Astronomical Algorithms", ch. 25 (low accuracy method).
+pub fn get_position(instant: impl Into<rtclock::Timestamp>) -> EquatorialPosition {
+    position_at(days_since_j2000(instant.into()))
+}
+
+/// Gets the sun's altitude and azimuth as seen by an observer, without
+/// refraction.
+pub fn get_horizontal_position(
+    instant: impl Into<rtclock::Timestamp>,
+    observer: &Observer,
+) -> HorizontalPosition {
+    let days = days_since_j2000(instant.into());
+    position_at(days).to_horizontal(days, observer)
+}
+
+/// Gets sunrise, sunset and solar noon on the day containing `instant`.
+///
+/// Days run from local mean midnight to local mean midnight at the observer's
+/// longitude, so the result doesn't depend on any time zone.
+pub fn get_events(instant: impl Into<rtclock::Timestamp>, observer: &Observer) -> SunEvents {
+    let noon = solar_noon_days(instant.into(), observer);
+    SunEvents {
+        solar_noon: timestamp_from_days_since_j2000(noon),
+        daylight: crossing(noon, observer, SUNRISE_ALTITUDE - horizon_dip(observer)),
+    }
+}
+
+/// Finds the solar noon on the day containing `timestamp` in days since J2000.
+pub(crate) fn solar_noon_days(timestamp: rtclock::Timestamp, observer: &Observer) -> f64 {
+    let mut noon = local_midnight_days(timestamp, observer) + 0.5;
+    for _ in 0..ITERATIONS {
+        noon -= local_hour_angle(&position_at(noon), noon, observer) / HOUR_ANGLE_RATE;
+    }
+    noon
+}
+
+/// Finds when the sun's centre crosses `altitude` (in degrees) either side of
+/// the given solar noon.
+pub(crate) fn crossing(noon: f64, observer: &Observer, altitude: f64) -> Crossing {
+    let position = position_at(noon);
+    let cos_hour_angle = cos_crossing_hour_angle(&position, observer, altitude);
+    if cos_hour_angle > 1.0 {
+        return Crossing::AlwaysBelow;
+    }
+    if cos_hour_angle < -1.0 {
+        return Crossing::AlwaysAbove;
+    }
+
+    let altitude_at = |instant: rtclock::Timestamp| {
+        let days = days_since_j2000(instant);
+        position_at(days).to_horizontal(days, observer).altitude
+    };
+    // The rise is assumed to fall within the half day before noon and the set
+    // within the half day after, so each bracket holds exactly one crossing.
+    // If the sun only grazes the altitude, refining gives whichever end of the
+    // bracket comes closer
+    let noon = timestamp_from_days_since_j2000(noon);
+    Crossing::RiseSet {
+        rise: CROSSING_SEARCH.refine_crossing(
+            &altitude_at,
+            altitude,
+            noon - CROSSING_SEARCH.step,
+            noon,
+        ),
+        set: CROSSING_SEARCH.refine_crossing(
+            &altitude_at,
+            altitude,
+            noon,
+            noon + CROSSING_SEARCH.step,
+        ),
+    }
+}
+
+pub(crate) fn position_at(days: f64) -> EquatorialPosition {
+    ecliptic_position_at(days).to_equatorial(days)
+}
+
+/// Gets the sun's apparent ecliptic position at `days` since J2000.0.
+pub(crate) fn ecliptic_position_at(days: f64) -> EclipticPosition {
+    let t = ephemeris_days(days) / 36525.0;
+
+    let mean_longitude = 280.46646 + 36000.76983 * t + 0.0003032 * t * t;
+    let mean_anomaly = 357.52911 + 35999.05029 * t - 0.0001537 * t * t;
+    let eccentricity = 0.016708634 - 0.000042037 * t;
+    let equation_of_centre = (1.914602 - 0.004817 * t - 0.000014 * t * t) * sin_deg(mean_anomaly)
+        + (0.019993 - 0.000101 * t) * sin_deg(2.0 * mean_anomaly)
+        + 0.000289 * sin_deg(3.0 * mean_anomaly);
+    let true_longitude = mean_longitude + equation_of_centre;
+    let true_anomaly = mean_anomaly + equation_of_centre;
+
+    // Correct for aberration (20.4") and nutation
+    let apparent_longitude = true_longitude - 0.00569 + nutation_in_longitude(days);
+
+    let distance_au = 1.000001018 * (1.0 - eccentricity * eccentricity)
+        / (1.0 + eccentricity * cos_deg(true_anomaly));
+
+    EclipticPosition {
+        longitude: normalize_degrees(apparent_longitude),
+        latitude: 0.0,
+        distance: distance_au * AU_KM,
+    }
+}
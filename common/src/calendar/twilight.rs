@@ -0,0 +1,128 @@
+use crate::calendar::sun::{self, Crossing};
+use crate::calendar::{Observer, days_since_j2000, timestamp_from_days_since_j2000};
+use crate::rtclock;
+
+pub(crate) const CIVIL_ALTITUDE: f64 = -6.0;
+pub(crate) const NAUTICAL_ALTITUDE: f64 = -12.0;
+pub(crate) const ASTRONOMICAL_ALTITUDE: f64 = -18.0;
+
+/// Photographers' golden hour, when the sun is low enough to give warm, soft
+/// light.
+const GOLDEN_HOUR_ALTITUDES: (f64, f64) = (-4.0, 6.0);
+/// Blue hour, when the sun is just far enough below the horizon that the sky
+/// is a deep blue.
+const BLUE_HOUR_ALTITUDES: (f64, f64) = (-6.0, -4.0);
+
+/// A span of time from `start` to `end`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interval {
+    pub start: rtclock::Timestamp,
+    pub end: rtclock::Timestamp,
+}
+
+/// The morning and evening periods during which the sun is within a band of
+/// altitudes. Either is `None` if the sun doesn't enter the band during that
+/// half of the day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Window {
+    pub morning: Option<Interval>,
+    pub evening: Option<Interval>,
+}
+
+/// Twilight periods on a given day.
+///
+/// For each kind of twilight, `rise` is dawn (the start of morning twilight)
+/// and `set` is dusk (the end of evening twilight). `AlwaysAbove` means the
+/// sky never gets that dark, and `AlwaysBelow` means it never gets that light.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Twilight {
+    pub civil: Crossing,
+    pub nautical: Crossing,
+    pub astronomical: Crossing,
+    pub golden_hour: Window,
+    pub blue_hour: Window,
+}
+
+/// Gets the twilight periods on the day containing `instant`. Days are
+/// defined as for `sun::get_events`.
+pub fn get_twilight(instant: impl Into<rtclock::Timestamp>, observer: &Observer) -> Twilight {
+    let noon = sun::solar_noon_days(instant.into(), observer);
+    Twilight {
+        civil: sun::crossing(noon, observer, CIVIL_ALTITUDE),
+        nautical: sun::crossing(noon, observer, NAUTICAL_ALTITUDE),
+        astronomical: sun::crossing(noon, observer, ASTRONOMICAL_ALTITUDE),
+        golden_hour: window(noon, observer, GOLDEN_HOUR_ALTITUDES),
+        blue_hour: window(noon, observer, BLUE_HOUR_ALTITUDES),
+    }
+}
+
+/// Finds the night around `instant`, during which the sun is below
+/// `altitude`, as (dusk, dawn) in days since J2000.0. Before dawn this is the
+/// night in progress, and otherwise the coming night. Returns `None` if it
+/// never gets that dark.
+pub(crate) fn night(
+    instant: rtclock::Timestamp,
+    observer: &Observer,
+    altitude: f64,
+) -> Option<(f64, f64)> {
+    let crossing = |noon: f64| sun::crossing(noon, observer, altitude);
+    // During polar night, nights run from noon to noon
+    let dawn = |noon: f64| match crossing(noon) {
+        Crossing::RiseSet { rise, .. } => Some(days_since_j2000(rise)),
+        Crossing::AlwaysBelow => Some(noon),
+        Crossing::AlwaysAbove => None,
+    };
+    let dusk = |noon: f64| match crossing(noon) {
+        Crossing::RiseSet { set, .. } => Some(days_since_j2000(set)),
+        Crossing::AlwaysBelow => Some(noon),
+        Crossing::AlwaysAbove => None,
+    };
+
+    let mut noon = sun::solar_noon_days(instant, observer);
+    if dawn(noon).is_some_and(|dawn| days_since_j2000(instant) < dawn) {
+        noon -= 1.0;
+    }
+    Some((dusk(noon)?, dawn(noon + 1.0)?))
+}
+
+/// Finds when the sun is between the `(low, high)` altitudes. Each half of the
+/// day is considered separately, so when the sun never leaves the band the
+/// windows are clipped at solar midnight and noon.
+fn window(noon: f64, observer: &Observer, (low, high): (f64, f64)) -> Window {
+    let low_crossing = sun::crossing(noon, observer, low);
+    let high_crossing = sun::crossing(noon, observer, high);
+
+    let midnight_before = timestamp_from_days_since_j2000(noon - 0.5);
+    let noon_timestamp = timestamp_from_days_since_j2000(noon);
+    let midnight_after = timestamp_from_days_since_j2000(noon + 0.5);
+
+    let morning_start = match low_crossing {
+        Crossing::RiseSet { rise, .. } => Some(rise),
+        Crossing::AlwaysAbove => Some(midnight_before),
+        Crossing::AlwaysBelow => None,
+    };
+    let morning_end = match high_crossing {
+        Crossing::RiseSet { rise, .. } => Some(rise),
+        Crossing::AlwaysBelow => Some(noon_timestamp),
+        Crossing::AlwaysAbove => None,
+    };
+    let evening_start = match high_crossing {
+        Crossing::RiseSet { set, .. } => Some(set),
+        Crossing::AlwaysBelow => Some(noon_timestamp),
+        Crossing::AlwaysAbove => None,
+    };
+    let evening_end = match low_crossing {
+        Crossing::RiseSet { set, .. } => Some(set),
+        Crossing::AlwaysAbove => Some(midnight_after),
+        Crossing::AlwaysBelow => None,
+    };
+
+    Window {
+        morning: morning_start
+            .zip(morning_end)
+            .map(|(start, end)| Interval { start, end }),
+        evening: evening_start
+            .zip(evening_end)
+            .map(|(start, end)| Interval { start, end }),
+    }
+}
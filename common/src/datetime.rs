@@ -0,0 +1,196 @@
+use crate::rtclock::Timestamp;
+
+const SECS_PER_DAY: i64 = 86_400;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Gets the weekday from its ISO 8601 number, 1 (Monday) to 7 (Sunday).
+    pub fn from_number(number: u8) -> Option<Self> {
+        use Weekday::*;
+        match number {
+            1 => Some(Monday),
+            2 => Some(Tuesday),
+            3 => Some(Wednesday),
+            4 => Some(Thursday),
+            5 => Some(Friday),
+            6 => Some(Saturday),
+            7 => Some(Sunday),
+            _ => None,
+        }
+    }
+
+    /// The ISO 8601 number of the weekday, 1 (Monday) to 7 (Sunday).
+    pub fn number(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    pub fn abbreviation(&self) -> &'static str {
+        use Weekday::*;
+        match self {
+            Monday => "Mon",
+            Tuesday => "Tue",
+            Wednesday => "Wed",
+            Thursday => "Thu",
+            Friday => "Fri",
+            Saturday => "Sat",
+            Sunday => "Sun",
+        }
+    }
+}
+
+/// A date and time in the proleptic Gregorian calendar.
+///
+/// Fields aren't validated, so a `DateTime` built by hand may be out of range
+/// (e.g. Feb 30). `to_timestamp` treats such values as offsets from the start
+/// of the month, so Feb 30 becomes Mar 1 or 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    pub year: i32,
+    /// Month of the year, 1 (January) to 12 (December).
+    pub month: u8,
+    /// Day of the month, starting at 1.
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl DateTime {
+    /// Splits a timestamp into its UTC date and time.
+    pub fn from_timestamp(timestamp: impl Into<Timestamp>) -> Self {
+        let secs = timestamp.into().secs();
+        let days = secs.div_euclid(SECS_PER_DAY);
+        let secs_of_day = secs.rem_euclid(SECS_PER_DAY);
+        let (year, month, day) = civil_from_days(days);
+        Self {
+            year,
+            month,
+            day,
+            hour: (secs_of_day / 3600) as u8,
+            minute: (secs_of_day % 3600 / 60) as u8,
+            second: (secs_of_day % 60) as u8,
+        }
+    }
+
+    /// Gets the timestamp of this date and time, interpreted as UTC.
+    pub fn to_timestamp(&self) -> Timestamp {
+        let days = days_from_civil(self.year, self.month, self.day);
+        Timestamp::from_secs(
+            days * SECS_PER_DAY
+                + self.hour as i64 * 3600
+                + self.minute as i64 * 60
+                + self.second as i64,
+        )
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        weekday_from_days(days_from_civil(self.year, self.month, self.day))
+    }
+
+    /// Day of the year, starting at 1 on Jan 1.
+    pub fn day_of_year(&self) -> u16 {
+        (days_from_civil(self.year, self.month, self.day) - days_from_civil(self.year, 1, 1) + 1)
+            as u16
+    }
+
+    /// Gets the ISO 8601 week as (week-numbering year, week). Weeks start on
+    /// Monday, and week 1 is the one containing the year's first Thursday, so
+    /// the first and last few days of a year may belong to a week of the
+    /// adjacent year.
+    pub fn iso_week(&self) -> (i32, u8) {
+        let week = (self.day_of_year() as i32 - self.weekday().number() as i32 + 10).div_euclid(7);
+        if week < 1 {
+            (self.year - 1, iso_weeks_in_year(self.year - 1))
+        } else if week > iso_weeks_in_year(self.year) as i32 {
+            (self.year + 1, 1)
+        } else {
+            (self.year, week as u8)
+        }
+    }
+}
+
+impl From<Timestamp> for DateTime {
+    fn from(timestamp: Timestamp) -> Self {
+        Self::from_timestamp(timestamp)
+    }
+}
+
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+pub fn month_abbreviation(month: u8) -> &'static str {
+    const ABBREVIATIONS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    ABBREVIATIONS
+        .get((month as usize).wrapping_sub(1))
+        .copied()
+        .unwrap_or("???")
+}
+
+/// Number of ISO 8601 weeks in a week-numbering year: 53 if the year starts
+/// on a Thursday, or is a leap year starting on a Wednesday, otherwise 52.
+fn iso_weeks_in_year(year: i32) -> u8 {
+    match weekday_from_days(days_from_civil(year, 1, 1)) {
+        Weekday::Thursday => 53,
+        Weekday::Wednesday if is_leap_year(year) => 53,
+        _ => 52,
+    }
+}
+
+fn weekday_from_days(days: i64) -> Weekday {
+    // Jan 1, 1970 was a Thursday
+    Weekday::from_number(((days + 3).rem_euclid(7) + 1) as u8).unwrap()
+}
+
+/// Converts a date to days since Jan 1, 1970. See Howard Hinnant, "chrono-
+/// Compatible Low-Level Date Algorithms".
+pub(crate) fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    // Count years from March, so the leap day falls at the end
+    let year = year as i64 - if month <= 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of `days_from_civil`, returning (year, month, day).
+fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as i32, month as u8, day as u8)
+}
@@ -1,6 +1,11 @@
 #![no_std]
 
 pub mod calendar;
+pub mod datetime;
 pub mod logic;
+pub mod pages;
 pub mod rtclock;
 pub mod theme;
+pub mod timescale;
+pub mod timezone;
+pub mod widgets;
@@ -1,45 +1,767 @@
+use core::fmt::Write;
+
 use embedded_graphics::{
-    mono_font::{MonoTextStyle, ascii::FONT_8X13_BOLD},
+    mono_font::{
+        MonoTextStyle,
+        ascii::{FONT_5X8, FONT_8X13_BOLD},
+    },
     prelude::*,
     primitives::PrimitiveStyle,
-    text::{Alignment, Text},
 };
 
-use crate::{calendar::moon, rtclock::RealTimeClock, theme::Theme};
+use crate::{
+    calendar::{
+        Observer, moon, planets,
+        seasons::{self, SeasonMarker},
+        sun, twilight,
+    },
+    datetime::{DateTime, month_abbreviation},
+    rtclock::{RealTimeClock, Timestamp},
+    theme::Theme,
+    timescale,
+    timezone::TimeZone,
+    widgets::{
+        Widget,
+        daylight::DaylightChart,
+        label::Label,
+        layout::{Align, Padding, Stack},
+        month::MonthGrid,
+        moon::MoonDisk,
+        progress_bar::ProgressBar,
+        sun_path::SunPath,
+    },
+};
+
+/// Vertical space between sections of the frame, in pixels.
+const SECTION_GAP: u32 = 5;
+
+/// Height of the plot of the sun's path on the sun page, in pixels.
+const SUN_PATH_HEIGHT: u32 = 96;
+
+/// Height of the plot of the year's day lengths on the daylight page, in
+/// pixels.
+const DAYLIGHT_CHART_HEIGHT: u32 = 96;
+
+/// Diameter of the picture of the moon next to its phase and illumination,
+/// in pixels.
+const MOON_DIAMETER: u32 = 24;
+
+/// Diameter of the picture of the moon on the moon page, in pixels.
+const MOON_PAGE_DIAMETER: u32 = 56;
 
 pub fn draw_frame<Color: PixelColor, Error>(
     draw_target: &mut impl DrawTarget<Color = Color, Error = Error>,
     theme: &impl Theme<Color = Color>,
     clock: &impl RealTimeClock,
+    observer: &Observer,
+    time_zone: &TimeZone,
 ) -> Result<(), Error> {
-    draw_target
-        .bounding_box()
-        .into_styled(PrimitiveStyle::with_fill(theme.background()))
-        .draw(draw_target)?;
+    let now = Timestamp::from(clock.get_time());
 
-    let moon_phase = moon::get_phase(clock.get_time());
+    let (moon_phase, moon_illumination) = moon_phase_and_illumination(now);
     let moon_phase_label = moon::get_phase_label(moon_phase);
-    let moon_illumination = moon::get_illumination(moon_phase);
 
-    let mut buf = [0u8; 64];
-    let text = format_no_std::show(
-        &mut buf,
+    let next_phase = moon::get_next_phases(now).next().unwrap();
+    let mut next_phase_buf = [0u8; 48];
+    let next_phase_text = format_next_phase(&mut next_phase_buf, now, &next_phase, time_zone);
+
+    let mut sun_buf = [0u8; 48];
+    let sun_text = format_sun_events(&mut sun_buf, &sun::get_events(now, observer), time_zone);
+
+    let moon_events = moon::get_events(now, observer);
+
+    let today = time_zone.to_local(now);
+
+    let season = seasons::get_season(now);
+    let next_solstice = seasons::get_next_events(now)
+        .find(|event| event.marker.is_solstice())
+        .unwrap();
+    let mut solstice_buf = [0u8; 24];
+    let solstice_text = match days_between(&today, &time_zone.to_local(next_solstice.instant)) {
+        0 => "solstice today",
+        days => {
+            format_no_std::show(&mut solstice_buf, format_args!("{days}d to solstice")).unwrap()
+        }
+    };
+
+    let mut date_buf = [0u8; 24];
+    let date_text = format_date(&mut date_buf, &today);
+
+    let mut moon_buf = [0u8; 48];
+    let moon_text = format_no_std::show(
+        &mut moon_buf,
         format_args!(
-            "Phase {:02.0}%\nIllum {:02.0}%\n{}",
+            "Phase {:02.0}%\nIllum {:02.0}%",
             moon_phase * 100.0,
             moon_illumination * 100.0,
-            moon_phase_label
         ),
     )
     .unwrap();
 
-    Text::with_alignment(
-        text,
-        draw_target.bounding_box().center(),
-        MonoTextStyle::new(&FONT_8X13_BOLD, theme.text()),
-        Alignment::Center,
+    let mut moon_events_buf = [0u8; 32];
+    let moon_events_text = format_no_std::show(
+        &mut moon_events_buf,
+        format_args!(
+            "Moonrise {}\nMoonset  {}",
+            TimeOfDay(moon_events.rise, time_zone),
+            TimeOfDay(moon_events.set, time_zone),
+        ),
+    )
+    .unwrap();
+
+    let mut planets_buf = [0u8; 160];
+    let planets_text = format_planets(&mut planets_buf, now, observer, time_zone);
+
+    let large = MonoTextStyle::new(&FONT_8X13_BOLD, theme.text());
+    let small = MonoTextStyle::new(&FONT_5X8, theme.text());
+    let character_size = FONT_8X13_BOLD.character_size;
+
+    // A picture of the moon goes to the left of its phase and illumination
+    let moon = Stack::vertical((
+        Stack::horizontal((
+            MoonDisk {
+                diameter: MOON_DIAMETER,
+                illumination: moon_illumination,
+                bright_limb_tilt: moon::get_bright_limb_tilt(now, observer),
+                theme,
+            },
+            Label::new(moon_text, large),
+        ))
+        .gap(character_size.width),
+        Label::new(moon_phase_label, large),
+    ));
+
+    // The season's progress bar fills the rest of the line with its label
+    let season = Stack::vertical((
+        Stack::horizontal((
+            Label::new(season.label(observer), large),
+            ProgressBar {
+                progress: season.progress(now),
+                theme,
+            }
+            .padded(Padding::all(2))
+            .flex(1),
+        ))
+        .gap(character_size.width)
+        .align(Align::Stretch)
+        .fixed(character_size.height),
+        Label::new(solstice_text, large),
+    ));
+
+    let page = Stack::vertical((
+        Label::new(date_text, large),
+        moon,
+        Label::new(next_phase_text, large),
+        Label::new(sun_text, large),
+        Label::new(moon_events_text, large),
+        season,
+        Label::new(planets_text, small),
+    ))
+    .gap(SECTION_GAP)
+    .justify(Align::Center);
+    draw_page(draw_target, theme, &page)
+}
+
+/// Draws a page showing the time in each of the standard time scales, the
+/// Julian Date, and the sidereal time for `observer`.
+pub fn draw_time_standards<Color: PixelColor, Error>(
+    draw_target: &mut impl DrawTarget<Color = Color, Error = Error>,
+    theme: &impl Theme<Color = Color>,
+    clock: &impl RealTimeClock,
+    observer: &Observer,
+    time_zone: &TimeZone,
+) -> Result<(), Error> {
+    let now = Timestamp::from(clock.get_time());
+    let today = time_zone.to_local(now);
+
+    let mut date_buf = [0u8; 24];
+    let date_text = format_date(&mut date_buf, &today);
+
+    // Shown to the whole second, so the fraction of TT's offset is dropped
+    let utc = DateTime::from_timestamp(now);
+    let tai = timescale::tai_minus_utc(now).map(|offset| DateTime::from_timestamp(now + offset));
+    let tt = DateTime::from_timestamp(now + libm::floor(timescale::tt_minus_utc(now)) as i64);
+    let mut scales_buf = [0u8; 64];
+    let scales_text = format_no_std::show(
+        &mut scales_buf,
+        format_args!(
+            "Local {}\nUTC   {}\nTAI   {}\nTT    {}",
+            ClockTime(Some(today)),
+            ClockTime(Some(utc)),
+            ClockTime(tai),
+            ClockTime(Some(tt)),
+        ),
+    )
+    .unwrap();
+
+    let mut julian_date_buf = [0u8; 40];
+    let julian_date_text = format_no_std::show(
+        &mut julian_date_buf,
+        format_args!(
+            "JD {:.4}\nMJD {:.4}",
+            timescale::julian_date(now),
+            timescale::modified_julian_date(now),
+        ),
+    )
+    .unwrap();
+
+    // Sidereal time is an angle, but reads like a clock with 15 degrees to
+    // the hour
+    let sidereal_time = |degrees: f64| {
+        let secs = libm::round(degrees / 15.0 * 3600.0) as i64;
+        Some(DateTime::from_timestamp(Timestamp::from_secs(secs)))
+    };
+    let mut sidereal_buf = [0u8; 40];
+    let sidereal_text = format_no_std::show(
+        &mut sidereal_buf,
+        format_args!(
+            "GMST  {}\nLST   {}",
+            ClockTime(sidereal_time(timescale::greenwich_mean_sidereal_time(now))),
+            ClockTime(sidereal_time(timescale::local_sidereal_time(now, observer))),
+        ),
+    )
+    .unwrap();
+
+    let large = MonoTextStyle::new(&FONT_8X13_BOLD, theme.text());
+    let page = Stack::vertical((
+        Label::new(date_text, large),
+        Label::new(scales_text, large),
+        Label::new(julian_date_text, large),
+        Label::new(sidereal_text, large),
+    ))
+    .gap(SECTION_GAP)
+    .justify(Align::Center);
+    draw_page(draw_target, theme, &page)
+}
+
+/// Draws a page about the sun today: the path it takes across the sky, when
+/// it rises and sets, and the twilight either side.
+pub fn draw_sun_page<Color: PixelColor, Error>(
+    draw_target: &mut impl DrawTarget<Color = Color, Error = Error>,
+    theme: &impl Theme<Color = Color>,
+    clock: &impl RealTimeClock,
+    observer: &Observer,
+    time_zone: &TimeZone,
+) -> Result<(), Error> {
+    let now = Timestamp::from(clock.get_time());
+    let today = time_zone.to_local(now);
+    let events = sun::get_events(now, observer);
+    let twilight = twilight::get_twilight(now, observer);
+
+    let mut date_buf = [0u8; 24];
+    let date_text = format_date(&mut date_buf, &today);
+
+    let mut sun_buf = [0u8; 48];
+    let sun_text = format_sun_events(&mut sun_buf, &events, time_zone);
+
+    let mut daylight_buf = [0u8; 24];
+    let daylight_text = format_no_std::show(
+        &mut daylight_buf,
+        format_args!("Daylight {}", HoursMinutes(events.day_length())),
     )
-    .draw(draw_target)?;
+    .unwrap();
+
+    let mut twilight_buf = [0u8; 96];
+    let mut writer = format_no_std::WriteTo::new(&mut twilight_buf);
+    for (name, crossing) in [
+        ("Civil", twilight.civil),
+        ("Nautical", twilight.nautical),
+        ("Astro", twilight.astronomical),
+    ] {
+        writeln!(
+            writer,
+            "{name:<9}{} - {}",
+            TimeOfDay(crossing.rise(), time_zone),
+            TimeOfDay(crossing.set(), time_zone),
+        )
+        .unwrap();
+    }
+    let twilight_text = writer.as_str().unwrap().trim_end();
+
+    // The date goes above the sun's path, and everything else below it
+    let large = MonoTextStyle::new(&FONT_8X13_BOLD, theme.text());
+    let muted = MonoTextStyle::new(&FONT_5X8, theme.muted());
+    let page = Stack::vertical((
+        Label::new(date_text, large).padded(Padding::symmetric(0, SECTION_GAP)),
+        SunPath {
+            instant: now,
+            observer: *observer,
+            theme,
+        }
+        .fixed(SUN_PATH_HEIGHT),
+        Stack::vertical((
+            Label::new(sun_text, large),
+            Label::new(daylight_text, large),
+            Label::new(twilight_text, muted),
+        ))
+        .gap(SECTION_GAP)
+        .justify(Align::Center)
+        .flex(1),
+    ))
+    .align(Align::Stretch);
+    draw_page(draw_target, theme, &page)
+}
+
+/// Draws a page about the moon today: how it looks, when it rises and sets,
+/// and when the next principal phases are.
+pub fn draw_moon_page<Color: PixelColor, Error>(
+    draw_target: &mut impl DrawTarget<Color = Color, Error = Error>,
+    theme: &impl Theme<Color = Color>,
+    clock: &impl RealTimeClock,
+    observer: &Observer,
+    time_zone: &TimeZone,
+) -> Result<(), Error> {
+    let now = Timestamp::from(clock.get_time());
+    let today = time_zone.to_local(now);
+    let (phase, illumination) = moon_phase_and_illumination(now);
+    let events = moon::get_events(now, observer);
+
+    let mut date_buf = [0u8; 24];
+    let date_text = format_date(&mut date_buf, &today);
+
+    let mut illumination_buf = [0u8; 40];
+    let illumination_text = format_no_std::show(
+        &mut illumination_buf,
+        format_args!(
+            "Illum {:.0}%\nDist {:.0} km",
+            illumination * 100.0,
+            moon::get_distance(now),
+        ),
+    )
+    .unwrap();
+
+    let mut events_buf = [0u8; 48];
+    let events_text = format_no_std::show(
+        &mut events_buf,
+        format_args!(
+            "Moonrise {}\nSouth    {}\nMoonset  {}",
+            TimeOfDay(events.rise, time_zone),
+            TimeOfDay(events.transit, time_zone),
+            TimeOfDay(events.set, time_zone),
+        ),
+    )
+    .unwrap();
+
+    // The next phase in full, and the three after it by date alone
+    let mut phases = moon::get_next_phases(now);
+    let next_phase = phases.next().unwrap();
+    let mut next_phase_buf = [0u8; 48];
+    let next_phase_text = format_next_phase(&mut next_phase_buf, now, &next_phase, time_zone);
+    let mut later_phases_buf = [0u8; 80];
+    let mut writer = format_no_std::WriteTo::new(&mut later_phases_buf);
+    for event in phases.take(3) {
+        let date = time_zone.to_local(event.instant);
+        writeln!(
+            writer,
+            "{:02} {} {}",
+            date.day,
+            month_abbreviation(date.month),
+            event.label(),
+        )
+        .unwrap();
+    }
+    let later_phases_text = writer.as_str().unwrap().trim_end();
+
+    // The date goes above the moon, and everything else below it
+    let large = MonoTextStyle::new(&FONT_8X13_BOLD, theme.text());
+    let muted = MonoTextStyle::new(&FONT_5X8, theme.muted());
+    let page = Stack::vertical((
+        Label::new(date_text, large).padded(Padding::symmetric(0, SECTION_GAP)),
+        MoonDisk {
+            diameter: MOON_PAGE_DIAMETER,
+            illumination,
+            bright_limb_tilt: moon::get_bright_limb_tilt(now, observer),
+            theme,
+        }
+        .fixed(MOON_PAGE_DIAMETER),
+        Stack::vertical((
+            Label::new(moon::get_phase_label(phase), large),
+            Label::new(illumination_text, large),
+            Label::new(events_text, large),
+            Label::new(next_phase_text, large),
+            Label::new(later_phases_text, muted),
+        ))
+        .gap(SECTION_GAP)
+        .justify(Align::Center)
+        .flex(1),
+    ))
+    .align(Align::Stretch);
+    draw_page(draw_target, theme, &page)
+}
+
+/// Draws a page about the planets: which can be seen tonight, and when each
+/// of them rises, crosses the meridian and sets today.
+pub fn draw_planets_page<Color: PixelColor, Error>(
+    draw_target: &mut impl DrawTarget<Color = Color, Error = Error>,
+    theme: &impl Theme<Color = Color>,
+    clock: &impl RealTimeClock,
+    observer: &Observer,
+    time_zone: &TimeZone,
+) -> Result<(), Error> {
+    let now = Timestamp::from(clock.get_time());
+    let today = time_zone.to_local(now);
+
+    let mut date_buf = [0u8; 24];
+    let date_text = format_date(&mut date_buf, &today);
+
+    let mut tonight_buf = [0u8; 160];
+    let tonight_text = match format_planets(&mut tonight_buf, now, observer, time_zone) {
+        "" => "None visible",
+        text => text,
+    };
+
+    let mut events_buf = [0u8; 192];
+    let mut writer = format_no_std::WriteTo::new(&mut events_buf);
+    writeln!(
+        writer,
+        "{:<7} {:<5} {:<5} {:<5}",
+        "", "Rise", "South", "Set"
+    )
+    .unwrap();
+    for planet in planets::Planet::ALL {
+        let events = planets::get_events(planet, now, observer);
+        writeln!(
+            writer,
+            "{:<7} {} {} {}",
+            planet.name(),
+            TimeOfDay(events.rise, time_zone),
+            TimeOfDay(events.transit, time_zone),
+            TimeOfDay(events.set, time_zone),
+        )
+        .unwrap();
+    }
+    let events_text = writer.as_str().unwrap().trim_end();
+
+    let large = MonoTextStyle::new(&FONT_8X13_BOLD, theme.text());
+    let small = MonoTextStyle::new(&FONT_5X8, theme.text());
+    let muted = MonoTextStyle::new(&FONT_5X8, theme.muted());
+    let page = Stack::vertical((
+        Label::new(date_text, large),
+        Label::new("Tonight", large),
+        Label::new(tonight_text, small),
+        Label::new("Today", large),
+        Label::new(events_text, muted),
+    ))
+    .gap(SECTION_GAP)
+    .justify(Align::Center);
+    draw_page(draw_target, theme, &page)
+}
+
+/// Draws a page with a calendar of the current month, showing the moon's
+/// phase on each day.
+pub fn draw_month_page<Color: PixelColor, Error>(
+    draw_target: &mut impl DrawTarget<Color = Color, Error = Error>,
+    theme: &impl Theme<Color = Color>,
+    clock: &impl RealTimeClock,
+    observer: &Observer,
+    time_zone: &TimeZone,
+) -> Result<(), Error> {
+    let now = Timestamp::from(clock.get_time());
+    let today = time_zone.to_local(now);
+
+    let mut month_buf = [0u8; 16];
+    let month_text = format_no_std::show(
+        &mut month_buf,
+        format_args!("{} {}", month_abbreviation(today.month), today.year),
+    )
+    .unwrap();
+
+    // The month's name goes above the grid, which fills the rest of the page
+    let large = MonoTextStyle::new(&FONT_8X13_BOLD, theme.text());
+    let page = Stack::vertical((
+        Label::new(month_text, large).padded(Padding::symmetric(0, SECTION_GAP)),
+        MonthGrid {
+            today,
+            time_zone: *time_zone,
+            observer: *observer,
+            theme,
+        }
+        .padded(Padding {
+            bottom: SECTION_GAP,
+            ..Padding::default()
+        })
+        .flex(1),
+    ))
+    .align(Align::Stretch);
+    draw_page(draw_target, theme, &page)
+}
+
+/// Draws a page charting how long the sun is up over the year, with today's
+/// day length and how it's changed since yesterday.
+pub fn draw_daylight_page<Color: PixelColor, Error>(
+    draw_target: &mut impl DrawTarget<Color = Color, Error = Error>,
+    theme: &impl Theme<Color = Color>,
+    clock: &impl RealTimeClock,
+    observer: &Observer,
+    time_zone: &TimeZone,
+) -> Result<(), Error> {
+    let now = Timestamp::from(clock.get_time());
+    let today = time_zone.to_local(now);
+    let day_length = sun::get_events(now, observer).day_length();
+    let yesterday_length = sun::get_events(now - 86_400, observer).day_length();
+
+    let mut date_buf = [0u8; 24];
+    let date_text = format_date(&mut date_buf, &today);
+
+    let mut daylight_buf = [0u8; 24];
+    let daylight_text = format_no_std::show(
+        &mut daylight_buf,
+        format_args!("Daylight {}", HoursMinutes(day_length)),
+    )
+    .unwrap();
+
+    let mut change_buf = [0u8; 32];
+    let change_text = format_no_std::show(
+        &mut change_buf,
+        format_args!(
+            "{} vs yesterday",
+            MinutesSeconds(day_length - yesterday_length)
+        ),
+    )
+    .unwrap();
+
+    let mut solstices_buf = [0u8; 64];
+    let mut writer = format_no_std::WriteTo::new(&mut solstices_buf);
+    for marker in [SeasonMarker::JuneSolstice, SeasonMarker::DecemberSolstice] {
+        let instant = seasons::get_instant(today.year, marker);
+        let date = time_zone.to_local(instant);
+        writeln!(
+            writer,
+            "{:02} {}  {:>7}",
+            date.day,
+            month_abbreviation(date.month),
+            HoursMinutes(sun::get_events(instant, observer).day_length()),
+        )
+        .unwrap();
+    }
+    let solstices_text = writer.as_str().unwrap().trim_end();
+
+    // The date goes above the chart, and everything else below it
+    let large = MonoTextStyle::new(&FONT_8X13_BOLD, theme.text());
+    let small = MonoTextStyle::new(&FONT_5X8, theme.text());
+    let muted = MonoTextStyle::new(&FONT_5X8, theme.muted());
+    let page = Stack::vertical((
+        Label::new(date_text, large).padded(Padding::symmetric(0, SECTION_GAP)),
+        DaylightChart {
+            today,
+            time_zone: *time_zone,
+            observer: *observer,
+            theme,
+        }
+        .fixed(DAYLIGHT_CHART_HEIGHT),
+        Stack::vertical((
+            Label::new(daylight_text, large),
+            Label::new(change_text, small),
+            Label::new(solstices_text, muted),
+        ))
+        .gap(SECTION_GAP)
+        .justify(Align::Center)
+        .flex(1),
+    ))
+    .align(Align::Stretch);
+    draw_page(draw_target, theme, &page)
+}
+
+/// Fills the screen with the theme's background, then draws `page` over all
+/// of it.
+fn draw_page<Color: PixelColor, Error>(
+    draw_target: &mut impl DrawTarget<Color = Color, Error = Error>,
+    theme: &impl Theme<Color = Color>,
+    page: &impl Widget<Color = Color>,
+) -> Result<(), Error> {
+    let bounds = draw_target.bounding_box();
+    bounds
+        .into_styled(PrimitiveStyle::with_fill(theme.background()))
+        .draw(draw_target)?;
+    page.draw(draw_target, bounds)
+}
+
+/// Gets the moon's phase and illumination, approximated and looked up in a
+/// table if the `illumination-table` feature is on.
+fn moon_phase_and_illumination(now: Timestamp) -> (f64, f64) {
+    #[cfg(feature = "illumination-table")]
+    {
+        let phase = moon::get_approximate_phase(now);
+        (phase, moon::get_illumination_from_phase(phase))
+    }
+    #[cfg(not(feature = "illumination-table"))]
+    {
+        (moon::get_phase(now), moon::get_illumination(now))
+    }
+}
+
+/// Counts the calendar days from `from` to `to`, ignoring the time of day.
+fn days_between(from: &DateTime, to: &DateTime) -> i64 {
+    let midnight = |date: &DateTime| {
+        DateTime {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            ..*date
+        }
+        .to_timestamp()
+    };
+    (midnight(to) - midnight(from)) / 86_400
+}
+
+/// Formats a date as e.g. "Sat 18 Oct 2025".
+fn format_date<'a>(buf: &'a mut [u8], date: &DateTime) -> &'a str {
+    format_no_std::show(
+        buf,
+        format_args!(
+            "{} {:02} {} {}",
+            date.weekday().abbreviation(),
+            date.day,
+            month_abbreviation(date.month),
+            date.year,
+        ),
+    )
+    .unwrap()
+}
+
+/// Lists the planets visible tonight, one per line, e.g.
+/// "Jupiter rises 21:14  -2.6".
+fn format_planets<'a>(
+    buf: &'a mut [u8],
+    now: Timestamp,
+    observer: &Observer,
+    time_zone: &TimeZone,
+) -> &'a str {
+    let mut writer = format_no_std::WriteTo::new(buf);
+    for visibility in planets::get_visible_tonight(now, observer) {
+        let name = visibility.planet.name();
+        let magnitude = visibility.magnitude;
+        let line = match (visibility.rise, visibility.set) {
+            (Some(rise), _) => writeln!(
+                writer,
+                "{name:<7} rises {} {magnitude:>+5.1}",
+                TimeOfDay(Some(rise), time_zone)
+            ),
+            (None, Some(set)) => writeln!(
+                writer,
+                "{name:<7} sets  {} {magnitude:>+5.1}",
+                TimeOfDay(Some(set), time_zone)
+            ),
+            (None, None) => writeln!(writer, "{name:<7} up all night{magnitude:>+5.1}"),
+        };
+        line.unwrap();
+    }
+    writer.as_str().unwrap().trim_end()
+}
+
+fn format_sun_events<'a>(
+    buf: &'a mut [u8],
+    events: &sun::SunEvents,
+    time_zone: &TimeZone,
+) -> &'a str {
+    let noon = TimeOfDay(Some(events.solar_noon), time_zone);
+    match events.daylight {
+        sun::Crossing::RiseSet { rise, set } => format_no_std::show(
+            buf,
+            format_args!(
+                "Sunrise {}\nNoon    {}\nSunset  {}",
+                TimeOfDay(Some(rise), time_zone),
+                noon,
+                TimeOfDay(Some(set), time_zone),
+            ),
+        )
+        .unwrap(),
+        sun::Crossing::AlwaysAbove => {
+            format_no_std::show(buf, format_args!("Sun up all day\nNoon    {noon}")).unwrap()
+        }
+        sun::Crossing::AlwaysBelow => "Sun down all day",
+    }
+}
+
+/// Formats the next principal phase with the time left until it and its local
+/// date and time, as e.g. "full moon\nin 3d 4h\n(Oct 21 14:12)".
+pub fn format_next_phase<'a>(
+    buf: &'a mut [u8],
+    now: Timestamp,
+    event: &moon::PhaseEvent,
+    time_zone: &TimeZone,
+) -> &'a str {
+    let mut countdown_buf = [0u8; 16];
+    let countdown = format_countdown(&mut countdown_buf, now, event.instant);
+    let date = time_zone.to_local(event.instant);
+    format_no_std::show(
+        buf,
+        format_args!(
+            "{}\nin {}\n({} {:02} {})",
+            event.label(),
+            countdown,
+            month_abbreviation(date.month),
+            date.day,
+            TimeOfDay(Some(event.instant), time_zone),
+        ),
+    )
+    .unwrap()
+}
+
+/// Formats the time remaining until `instant` as e.g. "3d 4h", or "4h 12m"
+/// when it's less than a day away.
+fn format_countdown(buf: &mut [u8], now: Timestamp, instant: Timestamp) -> &str {
+    let secs = (instant - now).max(0);
+    let (days, hours, minutes) = (secs / 86_400, secs % 86_400 / 3600, secs % 3600 / 60);
+    if days > 0 {
+        format_no_std::show(buf, format_args!("{days}d {hours}h")).unwrap()
+    } else {
+        format_no_std::show(buf, format_args!("{hours}h {minutes}m")).unwrap()
+    }
+}
+
+/// Displays a duration in seconds as e.g. "10h 26m".
+struct HoursMinutes(i64);
+
+impl core::fmt::Display for HoursMinutes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf = [0u8; 16];
+        let text = format_no_std::show(
+            &mut buf,
+            format_args!("{}h {:02}m", self.0 / 3600, self.0 % 3600 / 60),
+        )
+        .unwrap();
+        // Padded as a whole, so it lines up in columns
+        f.pad(text)
+    }
+}
+
+/// Displays a change in seconds with its sign, as e.g. "+2m13s".
+struct MinutesSeconds(i64);
+
+impl core::fmt::Display for MinutesSeconds {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.0 < 0 { '-' } else { '+' };
+        let secs = self.0.abs();
+        write!(f, "{sign}{}m{:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Displays the time of day on a clock as "HH:MM:SS", or "--:--:--" if there
+/// isn't one.
+struct ClockTime(Option<DateTime>);
+
+impl core::fmt::Display for ClockTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Some(time) => write!(f, "{:02}:{:02}:{:02}", time.hour, time.minute, time.second),
+            None => write!(f, "--:--:--"),
+        }
+    }
+}
+
+/// Displays the local time of day of an event as "HH:MM", or "--:--" if it
+/// doesn't happen.
+struct TimeOfDay<'a>(Option<Timestamp>, &'a TimeZone<'a>);
 
-    Ok(())
+impl core::fmt::Display for TimeOfDay<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Some(instant) => {
+                let time = self.1.to_local(instant);
+                write!(f, "{:02}:{:02}", time.hour, time.minute)
+            }
+            None => write!(f, "--:--"),
+        }
+    }
 }
@@ -0,0 +1,144 @@
+use embedded_graphics::prelude::*;
+
+use crate::calendar::{Observer, sun};
+use crate::logic;
+use crate::rtclock::{InstantSecs, RealTimeClock};
+use crate::theme::Theme;
+use crate::timezone::TimeZone;
+
+/// One of the screens the display can show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Page {
+    /// Today's moon, sun, season and planets at a glance.
+    Main,
+    /// The moon's phase, rise and set today, and its next phases.
+    Moon,
+    /// The sun's path across the sky today, and twilight.
+    Sun,
+    /// How long the sun is up over the year.
+    Daylight,
+    /// This month, with the moon's phase on each day.
+    Month,
+    /// Which planets can be seen tonight, and their rise and set times.
+    Planets,
+    /// The time in UTC, TAI and TT, the Julian Date and sidereal time.
+    TimeStandards,
+}
+
+impl Page {
+    pub const ALL: [Page; 7] = [
+        Page::Main,
+        Page::Moon,
+        Page::Sun,
+        Page::Daylight,
+        Page::Month,
+        Page::Planets,
+        Page::TimeStandards,
+    ];
+
+    /// Short name for picking the page by hand, e.g. on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Page::Main => "main",
+            Page::Moon => "moon",
+            Page::Sun => "sun",
+            Page::Daylight => "daylight",
+            Page::Month => "month",
+            Page::Planets => "planets",
+            Page::TimeStandards => "time-standards",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|page| page.name() == name)
+    }
+
+    pub fn draw<Color: PixelColor, Error>(
+        &self,
+        draw_target: &mut impl DrawTarget<Color = Color, Error = Error>,
+        theme: &impl Theme<Color = Color>,
+        clock: &impl RealTimeClock,
+        observer: &Observer,
+        time_zone: &TimeZone,
+    ) -> Result<(), Error> {
+        match self {
+            Page::Main => logic::draw_frame(draw_target, theme, clock, observer, time_zone),
+            Page::Moon => logic::draw_moon_page(draw_target, theme, clock, observer, time_zone),
+            Page::Sun => logic::draw_sun_page(draw_target, theme, clock, observer, time_zone),
+            Page::Daylight => {
+                logic::draw_daylight_page(draw_target, theme, clock, observer, time_zone)
+            }
+            Page::Month => logic::draw_month_page(draw_target, theme, clock, observer, time_zone),
+            Page::Planets => {
+                logic::draw_planets_page(draw_target, theme, clock, observer, time_zone)
+            }
+            Page::TimeStandards => {
+                logic::draw_time_standards(draw_target, theme, clock, observer, time_zone)
+            }
+        }
+    }
+}
+
+/// Decides which page to show when the display refreshes.
+pub trait Schedule {
+    fn page_at(&self, instant: InstantSecs) -> Page;
+}
+
+/// Any function from the time to a page is a schedule.
+impl<F: Fn(InstantSecs) -> Page> Schedule for F {
+    fn page_at(&self, instant: InstantSecs) -> Page {
+        self(instant)
+    }
+}
+
+/// Always shows the same page.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fixed(pub Page);
+
+impl Schedule for Fixed {
+    fn page_at(&self, _instant: InstantSecs) -> Page {
+        self.0
+    }
+}
+
+/// Shows each of `pages` in turn for `period_secs`. With the period set to
+/// how often the display refreshes, this moves on to the next page at each
+/// refresh.
+///
+/// Turns are counted from the unix epoch, so which page is showing depends
+/// only on the time, not on when the display was switched on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rotate<'a> {
+    pub pages: &'a [Page],
+    pub period_secs: u64,
+}
+
+impl Schedule for Rotate<'_> {
+    fn page_at(&self, instant: InstantSecs) -> Page {
+        let turn = instant.ticks() / self.period_secs.max(1);
+        self.pages
+            .get((turn % self.pages.len().max(1) as u64) as usize)
+            .copied()
+            .unwrap_or(Page::Main)
+    }
+}
+
+/// Shows one page while the sun is up for `observer`, and another while it's
+/// down.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DayNight {
+    pub day: Page,
+    pub night: Page,
+    pub observer: Observer,
+}
+
+impl Schedule for DayNight {
+    fn page_at(&self, instant: InstantSecs) -> Page {
+        let altitude = sun::get_horizontal_position(instant, &self.observer).altitude;
+        if altitude > sun::SUNRISE_ALTITUDE {
+            self.day
+        } else {
+            self.night
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use core::ops::{Add, Sub};
+
 pub type Instant<const NOM: u32> = fugit::Instant<u64, NOM, 1>;
 pub type InstantSecs = Instant<1>;
 
@@ -5,3 +7,58 @@ pub type InstantSecs = Instant<1>;
 pub trait RealTimeClock {
     fn get_time(&self) -> InstantSecs;
 }
+
+/// Signed number of seconds relative to the unix epoch.
+///
+/// Unlike `InstantSecs` this can represent times before 1970, so it's what
+/// `calendar` computations work in. Anything accepting an `InstantSecs` can
+/// be converted losslessly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    pub const fn from_secs(secs: i64) -> Self {
+        Self(secs)
+    }
+
+    pub const fn secs(self) -> i64 {
+        self.0
+    }
+
+    /// Converts to an `InstantSecs`, or `None` if this is before the epoch.
+    pub fn to_instant(self) -> Option<InstantSecs> {
+        u64::try_from(self.0).ok().map(InstantSecs::from_ticks)
+    }
+}
+
+impl From<InstantSecs> for Timestamp {
+    fn from(instant: InstantSecs) -> Self {
+        Self(instant.ticks() as i64)
+    }
+}
+
+impl Add<i64> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, secs: i64) -> Self::Output {
+        Self(self.0 + secs)
+    }
+}
+
+impl Sub<i64> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, secs: i64) -> Self::Output {
+        Self(self.0 - secs)
+    }
+}
+
+/// The number of seconds between two timestamps, which is negative when `rhs`
+/// is later.
+impl Sub for Timestamp {
+    type Output = i64;
+
+    fn sub(self, rhs: Timestamp) -> Self::Output {
+        self.0 - rhs.0
+    }
+}
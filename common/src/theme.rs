@@ -1,6 +1,114 @@
+use embedded_graphics::{
+    pixelcolor::{BinaryColor, raw::RawU2},
+    prelude::PixelColor,
+};
+
+/// Colors for the roles things on screen play, so pages and widgets say what
+/// something is and each display decides how it looks. Only the background
+/// and text colors are required, and the rest fall back on those, so a
+/// display with two colors gets a workable palette for free.
 pub trait Theme {
-    type Color;
+    type Color: PixelColor;
 
     fn background(&self) -> Self::Color;
     fn text(&self) -> Self::Color;
+
+    /// Color for detail that should sit behind the text, such as guide lines
+    /// and shading. Defaults to the text color.
+    fn muted(&self) -> Self::Color {
+        self.text()
+    }
+
+    /// Color for picking out graphics on displays that have one to spare.
+    /// Defaults to the text color.
+    fn highlight(&self) -> Self::Color {
+        self.text()
+    }
+
+    /// Color for calling attention to one thing, such as today on a calendar.
+    /// Defaults to the highlight color.
+    fn accent(&self) -> Self::Color {
+        self.highlight()
+    }
+
+    /// Color for something that needs the reader's attention, more urgently
+    /// than the accent. Defaults to the accent color.
+    fn warning(&self) -> Self::Color {
+        self.accent()
+    }
+
+    /// Color of the lit part of the moon. Defaults to the highlight color.
+    fn moonlight(&self) -> Self::Color {
+        self.highlight()
+    }
+
+    /// Color of the sun. Defaults to the highlight color.
+    fn sunlight(&self) -> Self::Color {
+        self.highlight()
+    }
+}
+
+/// Palette for black and white displays, which draws everything but the
+/// background in `BinaryColor::On`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BinaryTheme;
+
+impl Theme for BinaryTheme {
+    type Color = BinaryColor;
+
+    fn background(&self) -> Self::Color {
+        BinaryColor::Off
+    }
+
+    fn text(&self) -> Self::Color {
+        BinaryColor::On
+    }
+}
+
+/// The four inks of the JD79661 e-paper panel, numbered as the panel encodes
+/// them in its two bits per pixel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum JD79661Color {
+    Black = 0b00,
+    #[default]
+    White = 0b01,
+    Yellow = 0b10,
+    Red = 0b11,
+}
+
+impl PixelColor for JD79661Color {
+    type Raw = RawU2;
+}
+
+/// Palette for the JD79661: white on black, with yellow for highlights such
+/// as the sun and the moon's lit part, and red for the accent and warnings.
+/// There's no grey, so muted detail is white too.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JD79661Theme;
+
+impl JD79661Theme {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Theme for JD79661Theme {
+    type Color = JD79661Color;
+
+    fn background(&self) -> Self::Color {
+        Self::Color::Black
+    }
+
+    fn text(&self) -> Self::Color {
+        Self::Color::White
+    }
+
+    fn highlight(&self) -> Self::Color {
+        Self::Color::Yellow
+    }
+
+    fn accent(&self) -> Self::Color {
+        Self::Color::Red
+    }
 }
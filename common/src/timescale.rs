@@ -0,0 +1,241 @@
+use crate::calendar::{
+    Observer, cos_deg, days_since_j2000, normalize_degrees, nutation_in_longitude, obliquity,
+    timestamp_from_days_since_j2000,
+};
+use crate::rtclock::Timestamp;
+
+/// Julian Date of the unix epoch, Jan 1, 1970 00:00 UTC.
+const UNIX_EPOCH_JULIAN_DATE: f64 = 2_440_587.5;
+
+/// Julian Date of the start of the Modified Julian Date count, Nov 17, 1858
+/// 00:00.
+const MODIFIED_JULIAN_DATE_EPOCH: f64 = 2_400_000.5;
+
+const SECS_PER_DAY: f64 = 86_400.0;
+
+/// Terrestrial time is defined to run exactly this many seconds ahead of
+/// atomic time.
+const TT_MINUS_TAI: f64 = 32.184;
+
+/// Leap seconds, as (timestamp, TAI - UTC in seconds from then on). UTC in its
+/// current form started in 1972, ten seconds behind atomic time.
+static LEAP_SECONDS: [(i64, i64); 28] = [
+    (63_072_000, 10),    // 1972-01-01
+    (78_796_800, 11),    // 1972-07-01
+    (94_694_400, 12),    // 1973-01-01
+    (126_230_400, 13),   // 1974-01-01
+    (157_766_400, 14),   // 1975-01-01
+    (189_302_400, 15),   // 1976-01-01
+    (220_924_800, 16),   // 1977-01-01
+    (252_460_800, 17),   // 1978-01-01
+    (283_996_800, 18),   // 1979-01-01
+    (315_532_800, 19),   // 1980-01-01
+    (362_793_600, 20),   // 1981-07-01
+    (394_329_600, 21),   // 1982-07-01
+    (425_865_600, 22),   // 1983-07-01
+    (489_024_000, 23),   // 1985-07-01
+    (567_993_600, 24),   // 1988-01-01
+    (631_152_000, 25),   // 1990-01-01
+    (662_688_000, 26),   // 1991-01-01
+    (709_948_800, 27),   // 1992-07-01
+    (741_484_800, 28),   // 1993-07-01
+    (773_020_800, 29),   // 1994-07-01
+    (820_454_400, 30),   // 1996-01-01
+    (867_715_200, 31),   // 1997-07-01
+    (915_148_800, 32),   // 1999-01-01
+    (1_136_073_600, 33), // 2006-01-01
+    (1_230_768_000, 34), // 2009-01-01
+    (1_341_100_800, 35), // 2012-07-01
+    (1_435_708_800, 36), // 2015-07-01
+    (1_483_228_800, 37), // 2017-01-01
+];
+
+/// Converts a timestamp to a Julian Date, i.e. (fractional) days since noon
+/// on Jan 1, 4713 BC, in UTC.
+pub fn julian_date(instant: impl Into<Timestamp>) -> f64 {
+    UNIX_EPOCH_JULIAN_DATE + instant.into().secs() as f64 / SECS_PER_DAY
+}
+
+/// Converts a timestamp to a Modified Julian Date, which counts days from
+/// midnight rather than noon, and from a more recent epoch.
+pub fn modified_julian_date(instant: impl Into<Timestamp>) -> f64 {
+    julian_date(instant) - MODIFIED_JULIAN_DATE_EPOCH
+}
+
+/// Converts a Julian Date in UTC back to a timestamp, rounded to the nearest
+/// second.
+pub fn timestamp_from_julian_date(julian_date: f64) -> Timestamp {
+    Timestamp::from_secs(libm::round((julian_date - UNIX_EPOCH_JULIAN_DATE) * SECS_PER_DAY) as i64)
+}
+
+/// The number of seconds atomic time (TAI) is ahead of UTC at `instant`, or
+/// `None` before 1972, when UTC was kept close to TAI by adjusting the length
+/// of its seconds instead of with leap seconds. No more leap seconds are
+/// assumed after the last in the table.
+pub fn tai_minus_utc(instant: impl Into<Timestamp>) -> Option<i64> {
+    let secs = instant.into().secs();
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(start, _)| secs >= *start)
+        .map(|(_, offset)| *offset)
+}
+
+/// The number of seconds terrestrial time (TT), which the sun, moon and
+/// planets' series are expressed in, is ahead of UTC at `instant`. This comes
+/// from the leap second table where there is one, or else `delta_t`.
+pub fn tt_minus_utc(instant: impl Into<Timestamp>) -> f64 {
+    tt_minus_utc_at(days_since_j2000(instant.into()))
+}
+
+/// Converts a timestamp to a Julian Ephemeris Date, i.e. a Julian Date in
+/// terrestrial time.
+pub fn julian_ephemeris_date(instant: impl Into<Timestamp>) -> f64 {
+    let instant = instant.into();
+    julian_date(instant) + tt_minus_utc(instant) / SECS_PER_DAY
+}
+
+/// Modelled difference between terrestrial time and universal time (UT1,
+/// which follows the earth's rotation) in seconds, ΔT, at `instant`. Uses
+/// Espenak and Meeus' polynomials, fitted to historical observations and
+/// extrapolated into the future.
+pub fn delta_t(instant: impl Into<Timestamp>) -> f64 {
+    delta_t_at(days_since_j2000(instant.into()))
+}
+
+/// Greenwich mean sidereal time in degrees [0, 360), i.e. the right
+/// ascension on the Greenwich meridian ignoring nutation. Divide by 15 for
+/// hours.
+pub fn greenwich_mean_sidereal_time(instant: impl Into<Timestamp>) -> f64 {
+    mean_sidereal_degrees(days_since_j2000(instant.into()))
+}
+
+/// Greenwich apparent sidereal time in degrees [0, 360), i.e. the true right
+/// ascension on the Greenwich meridian.
+pub fn greenwich_apparent_sidereal_time(instant: impl Into<Timestamp>) -> f64 {
+    apparent_sidereal_degrees(days_since_j2000(instant.into()))
+}
+
+/// Local apparent sidereal time for `observer` in degrees [0, 360), i.e. the
+/// right ascension on their meridian.
+pub fn local_sidereal_time(instant: impl Into<Timestamp>, observer: &Observer) -> f64 {
+    normalize_degrees(greenwich_apparent_sidereal_time(instant) + observer.longitude)
+}
+
+/// `tt_minus_utc` at `days` since J2000.0 (UTC).
+pub(crate) fn tt_minus_utc_at(days: f64) -> f64 {
+    match tai_minus_utc(timestamp_from_days_since_j2000(days)) {
+        Some(offset) => offset as f64 + TT_MINUS_TAI,
+        None => delta_t_at(days),
+    }
+}
+
+/// `delta_t` at `days` since J2000.0.
+pub(crate) fn delta_t_at(days: f64) -> f64 {
+    let year = 2000.0 + days / 365.25;
+    // Long-term parabola, which the polynomials are fitted to join up with
+    let parabola = |year: f64| {
+        let u = (year - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    };
+    let polynomial = |t: f64, coefficients: &[f64]| {
+        coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |sum, coefficient| sum * t + coefficient)
+    };
+    match year {
+        ..-500.0 => parabola(year),
+        ..500.0 => polynomial(
+            year / 100.0,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        ),
+        ..1600.0 => polynomial(
+            (year - 1000.0) / 100.0,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        ),
+        ..1700.0 => polynomial(year - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0]),
+        ..1800.0 => polynomial(
+            year - 1700.0,
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1_174_000.0],
+        ),
+        ..1860.0 => polynomial(
+            year - 1800.0,
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+        ),
+        ..1900.0 => polynomial(
+            year - 1860.0,
+            &[
+                7.62,
+                0.5737,
+                -0.251754,
+                0.01680668,
+                -0.0004473624,
+                1.0 / 233_174.0,
+            ],
+        ),
+        ..1920.0 => polynomial(
+            year - 1900.0,
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+        ),
+        ..1941.0 => polynomial(year - 1920.0, &[21.20, 0.84493, -0.076100, 0.0020936]),
+        ..1961.0 => polynomial(year - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0]),
+        ..1986.0 => polynomial(year - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0]),
+        ..2005.0 => polynomial(
+            year - 2000.0,
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+        ),
+        ..2050.0 => polynomial(year - 2000.0, &[62.92, 0.32217, 0.005589]),
+        ..2150.0 => parabola(year) - 0.5628 * (2150.0 - year),
+        _ => parabola(year),
+    }
+}
+
+/// Greenwich mean sidereal time in degrees [0, 360) at `days` since J2000.0.
+pub(crate) fn mean_sidereal_degrees(days: f64) -> f64 {
+    let t = days / 36525.0;
+    normalize_degrees(
+        280.46061837 + 360.98564736629 * days + 0.000387933 * t * t - t * t * t / 38_710_000.0,
+    )
+}
+
+/// Greenwich apparent sidereal time in degrees [0, 360) at `days` since
+/// J2000.0, which corrects the mean for nutation (the equation of the
+/// equinoxes).
+pub(crate) fn apparent_sidereal_degrees(days: f64) -> f64 {
+    normalize_degrees(
+        mean_sidereal_degrees(days) + nutation_in_longitude(days) * cos_deg(obliquity(days)),
+    )
+}
@@ -0,0 +1,362 @@
+use crate::datetime::{DateTime, days_from_civil, days_in_month, is_leap_year};
+use crate::rtclock::Timestamp;
+
+const SECS_PER_DAY: i64 = 86_400;
+
+/// Transitions happen at 02:00 local time unless the rule says otherwise.
+const DEFAULT_TRANSITION_TIME: i32 = 2 * 3600;
+
+/// Rules used when a zone has daylight saving time but doesn't say when, as
+/// in "EST5EDT". These are the current US rules, as used by glibc.
+const DEFAULT_RULES: (Rule, Rule) = (
+    Rule::MonthWeekDay {
+        month: 3,
+        week: 2,
+        weekday: 0,
+    },
+    Rule::MonthWeekDay {
+        month: 11,
+        week: 1,
+        weekday: 0,
+    },
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A zone abbreviation was missing, too short or contained invalid
+    /// characters.
+    InvalidName,
+    /// A UTC offset or transition time was missing or out of range.
+    InvalidOffset,
+    /// A daylight saving transition rule was malformed.
+    InvalidRule,
+    /// There was unexpected text after the end of the string.
+    TrailingCharacters,
+}
+
+/// The day of the year on which a daylight saving transition happens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Rule {
+    /// "Jn": day n (1 to 365), where Feb 29 is never counted.
+    Julian(u16),
+    /// "n": day n (0 to 365), counting Feb 29 in leap years.
+    ZeroBased(u16),
+    /// "Mm.w.d": day d (0 = Sunday) of week w (1 to 5, where 5 means the
+    /// last) of month m.
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+impl Rule {
+    /// Gets the day of the transition in `year`, as days since Jan 1, 1970.
+    fn days(&self, year: i32) -> i64 {
+        let jan_1 = days_from_civil(year, 1, 1);
+        match *self {
+            Rule::Julian(day) => {
+                let leap_day = if is_leap_year(year) && day >= 60 {
+                    1
+                } else {
+                    0
+                };
+                jan_1 + day as i64 - 1 + leap_day
+            }
+            Rule::ZeroBased(day) => jan_1 + day as i64,
+            Rule::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = days_from_civil(year, month, 1);
+                // Jan 1, 1970 was a Thursday (4)
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day =
+                    (weekday as i64 - first_weekday).rem_euclid(7) + (week as i64 - 1) * 7;
+                while day >= days_in_month(year, month) as i64 {
+                    day -= 7;
+                }
+                first + day
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct DaylightSaving<'a> {
+    name: &'a str,
+    offset: i32,
+    start: Rule,
+    /// Local (standard) time of day at which daylight saving starts, in
+    /// seconds. This can be negative or more than a day.
+    start_time: i32,
+    end: Rule,
+    /// Local (daylight) time of day at which daylight saving ends, in seconds.
+    end_time: i32,
+}
+
+/// A time zone described by a POSIX TZ string, such as
+/// `CET-1CEST,M3.5.0,M10.5.0/3`.
+///
+/// Note that, as in POSIX, offsets in the string are hours *west* of UTC, so
+/// CET (UTC+1) is written "CET-1". Offsets returned by methods here are the
+/// more usual seconds *east* of UTC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeZone<'a> {
+    name: &'a str,
+    offset: i32,
+    daylight_saving: Option<DaylightSaving<'a>>,
+}
+
+/// The offset from UTC in effect at some instant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Offset<'a> {
+    /// Seconds east of UTC.
+    pub secs: i32,
+    /// Zone abbreviation, e.g. "CEST".
+    pub name: &'a str,
+    pub is_daylight_saving: bool,
+}
+
+impl TimeZone<'static> {
+    pub const UTC: Self = Self {
+        name: "UTC",
+        offset: 0,
+        daylight_saving: None,
+    };
+}
+
+impl<'a> TimeZone<'a> {
+    pub fn parse(tz: &'a str) -> Result<Self, ParseError> {
+        let mut parser = Parser { rest: tz };
+
+        let name = parser.name()?;
+        let offset = -parser.offset()?;
+        if parser.rest.is_empty() {
+            return Ok(Self {
+                name,
+                offset,
+                daylight_saving: None,
+            });
+        }
+
+        let daylight_name = parser.name()?;
+        let daylight_offset = if parser.rest.is_empty() || parser.rest.starts_with(',') {
+            offset + 3600
+        } else {
+            -parser.offset()?
+        };
+
+        let ((start, start_time), (end, end_time)) = if parser.rest.is_empty() {
+            (
+                (DEFAULT_RULES.0, DEFAULT_TRANSITION_TIME),
+                (DEFAULT_RULES.1, DEFAULT_TRANSITION_TIME),
+            )
+        } else {
+            parser.expect(',', ParseError::InvalidRule)?;
+            let start = parser.rule()?;
+            parser.expect(',', ParseError::InvalidRule)?;
+            let end = parser.rule()?;
+            (start, end)
+        };
+
+        if !parser.rest.is_empty() {
+            return Err(ParseError::TrailingCharacters);
+        }
+
+        Ok(Self {
+            name,
+            offset,
+            daylight_saving: Some(DaylightSaving {
+                name: daylight_name,
+                offset: daylight_offset,
+                start,
+                start_time,
+                end,
+                end_time,
+            }),
+        })
+    }
+
+    /// Gets the offset from UTC in effect at `timestamp`.
+    pub fn offset_at(&self, timestamp: impl Into<Timestamp>) -> Offset<'a> {
+        let timestamp = timestamp.into();
+        let standard = Offset {
+            secs: self.offset,
+            name: self.name,
+            is_daylight_saving: false,
+        };
+        let Some(dst) = self.daylight_saving else {
+            return standard;
+        };
+
+        let year = DateTime::from_timestamp(timestamp + self.offset as i64).year;
+        let start = Timestamp::from_secs(
+            dst.start.days(year) * SECS_PER_DAY + (dst.start_time - self.offset) as i64,
+        );
+        let end = Timestamp::from_secs(
+            dst.end.days(year) * SECS_PER_DAY + (dst.end_time - dst.offset) as i64,
+        );
+
+        // In the southern hemisphere daylight saving spans the new year
+        let is_daylight_saving = if start <= end {
+            start <= timestamp && timestamp < end
+        } else {
+            !(end <= timestamp && timestamp < start)
+        };
+
+        if is_daylight_saving {
+            Offset {
+                secs: dst.offset,
+                name: dst.name,
+                is_daylight_saving: true,
+            }
+        } else {
+            standard
+        }
+    }
+
+    /// Converts `timestamp` to local civil time.
+    pub fn to_local(&self, timestamp: impl Into<Timestamp>) -> DateTime {
+        let timestamp = timestamp.into();
+        DateTime::from_timestamp(timestamp + self.offset_at(timestamp).secs as i64)
+    }
+
+    /// Converts local civil time back to a timestamp. Times repeated when
+    /// clocks go back resolve to the earlier (daylight saving) one, and times
+    /// skipped when clocks go forward are taken as standard time.
+    pub fn from_local(&self, local: &DateTime) -> Timestamp {
+        let naive = local.to_timestamp();
+        if let Some(dst) = self.daylight_saving {
+            let timestamp = naive - dst.offset as i64;
+            if self.offset_at(timestamp).is_daylight_saving {
+                return timestamp;
+            }
+        }
+        naive - self.offset as i64
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn expect(&mut self, c: char, error: ParseError) -> Result<(), ParseError> {
+        self.rest = self.rest.strip_prefix(c).ok_or(error)?;
+        Ok(())
+    }
+
+    /// Parses a zone abbreviation: either three or more letters, or any
+    /// alphanumerics and signs enclosed in angle brackets, like "<+0330>".
+    fn name(&mut self) -> Result<&'a str, ParseError> {
+        let (name, rest) = if let Some(quoted) = self.rest.strip_prefix('<') {
+            let end = quoted.find('>').ok_or(ParseError::InvalidName)?;
+            let name = &quoted[..end];
+            if !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-')
+            {
+                return Err(ParseError::InvalidName);
+            }
+            (name, &quoted[end + 1..])
+        } else {
+            let end = self
+                .rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(self.rest.len());
+            self.rest.split_at(end)
+        };
+
+        if name.len() < 3 {
+            return Err(ParseError::InvalidName);
+        }
+        self.rest = rest;
+        Ok(name)
+    }
+
+    /// Parses "[+-]hh[:mm[:ss]]" as seconds. Hours can go up to 24 for UTC
+    /// offsets.
+    fn offset(&mut self) -> Result<i32, ParseError> {
+        self.signed_time(24)
+    }
+
+    /// Parses a transition rule with an optional "/time" suffix.
+    fn rule(&mut self) -> Result<(Rule, i32), ParseError> {
+        let rule = if let Some(rest) = self.rest.strip_prefix('J') {
+            self.rest = rest;
+            match self.number()? {
+                day @ 1..=365 => Rule::Julian(day as u16),
+                _ => return Err(ParseError::InvalidRule),
+            }
+        } else if let Some(rest) = self.rest.strip_prefix('M') {
+            self.rest = rest;
+            let month = self.number()?;
+            self.expect('.', ParseError::InvalidRule)?;
+            let week = self.number()?;
+            self.expect('.', ParseError::InvalidRule)?;
+            let weekday = self.number()?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                return Err(ParseError::InvalidRule);
+            }
+            Rule::MonthWeekDay {
+                month: month as u8,
+                week: week as u8,
+                weekday: weekday as u8,
+            }
+        } else {
+            match self.number()? {
+                day @ 0..=365 => Rule::ZeroBased(day as u16),
+                _ => return Err(ParseError::InvalidRule),
+            }
+        };
+
+        let time = if let Some(rest) = self.rest.strip_prefix('/') {
+            self.rest = rest;
+            // RFC 8536 extends transition times to -167 to 167 hours
+            self.signed_time(167)?
+        } else {
+            DEFAULT_TRANSITION_TIME
+        };
+
+        Ok((rule, time))
+    }
+
+    fn signed_time(&mut self, max_hours: u32) -> Result<i32, ParseError> {
+        let sign = if let Some(rest) = self.rest.strip_prefix('-') {
+            self.rest = rest;
+            -1
+        } else {
+            if let Some(rest) = self.rest.strip_prefix('+') {
+                self.rest = rest;
+            }
+            1
+        };
+
+        let hours = self.number().map_err(|_| ParseError::InvalidOffset)?;
+        if hours > max_hours {
+            return Err(ParseError::InvalidOffset);
+        }
+        let mut secs = hours * 3600;
+        for multiplier in [60, 1] {
+            let Some(rest) = self.rest.strip_prefix(':') else {
+                break;
+            };
+            self.rest = rest;
+            let value = self.number().map_err(|_| ParseError::InvalidOffset)?;
+            if value > 59 {
+                return Err(ParseError::InvalidOffset);
+            }
+            secs += value * multiplier;
+        }
+        Ok(sign * secs as i32)
+    }
+
+    fn number(&mut self) -> Result<u32, ParseError> {
+        let end = self
+            .rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len());
+        let (digits, rest) = self.rest.split_at(end);
+        let number = digits.parse().map_err(|_| ParseError::InvalidRule)?;
+        self.rest = rest;
+        Ok(number)
+    }
+}
@@ -0,0 +1,77 @@
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use layout::{Align, Aligned, Padded, Padding, Sizing, WithSizing};
+
+pub mod daylight;
+pub mod label;
+pub mod layout;
+pub mod month;
+pub mod moon;
+pub mod progress_bar;
+pub mod sun_path;
+
+/// Something drawn into whatever area it's given, so it can be arranged with
+/// others using the containers in `layout`.
+pub trait Widget {
+    type Color: PixelColor;
+
+    /// The size this would like to be, given at most `available`. Widgets
+    /// that fill whatever they're given, such as plots, return `available`.
+    fn size(&self, available: Size) -> Size;
+
+    /// How this takes up space along the direction of a stack.
+    fn sizing(&self) -> Sizing {
+        Sizing::Auto
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error>;
+
+    /// Gives this exactly `length` pixels along a stack.
+    fn fixed(self, length: u32) -> WithSizing<Self>
+    where
+        Self: Sized,
+    {
+        WithSizing {
+            widget: self,
+            sizing: Sizing::Fixed(length),
+        }
+    }
+
+    /// Gives this a share of the space left along a stack, in proportion to
+    /// `weight`.
+    fn flex(self, weight: u32) -> WithSizing<Self>
+    where
+        Self: Sized,
+    {
+        WithSizing {
+            widget: self,
+            sizing: Sizing::Flex(weight),
+        }
+    }
+
+    fn padded(self, padding: Padding) -> Padded<Self>
+    where
+        Self: Sized,
+    {
+        Padded {
+            widget: self,
+            padding,
+        }
+    }
+
+    /// Places this within whatever area it's given.
+    fn aligned(self, horizontal: Align, vertical: Align) -> Aligned<Self>
+    where
+        Self: Sized,
+    {
+        Aligned {
+            widget: self,
+            horizontal,
+            vertical,
+        }
+    }
+}
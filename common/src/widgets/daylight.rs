@@ -0,0 +1,150 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle},
+};
+
+use crate::calendar::{
+    Observer,
+    seasons::{self, SeasonMarker},
+    sun,
+};
+use crate::datetime::{DateTime, is_leap_year};
+use crate::rtclock;
+use crate::theme::Theme;
+use crate::timezone::TimeZone;
+use crate::widgets::Widget;
+
+/// Diameter of the marker for today's day length, in pixels.
+const MARKER_DIAMETER: u32 = 7;
+
+/// Pixels between the dots of the lines marking 12 hours and the equinoxes
+/// and solstices.
+const DOT_SPACING: i32 = 3;
+
+const SECS_PER_HOUR: i64 = 3600;
+
+/// Plot of how long the sun is up on each day of the year containing `today`,
+/// from Jan 1 to Dec 31. Dotted lines mark 12 hours, which the plot crosses
+/// at the equinoxes, and the equinoxes and solstices, and a marker shows
+/// today.
+///
+/// Each column of pixels shows the day nearest it, so nothing is stored and
+/// days in between are skipped when the plot is narrower than the year.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DaylightChart<'a, T> {
+    /// Today's local date, which picks the year to show.
+    pub today: DateTime,
+    pub time_zone: TimeZone<'a>,
+    pub observer: Observer,
+    /// The plot is drawn in the text color over muted guide lines, and
+    /// today's marker is filled with the accent color.
+    pub theme: &'a T,
+}
+
+impl<T: Theme> DaylightChart<'_, T> {
+    /// Local noon on the given day of the year, counting from 0 on Jan 1.
+    fn noon(&self, day_of_year: i64) -> rtclock::Timestamp {
+        self.time_zone.from_local(&DateTime {
+            year: self.today.year,
+            month: 1,
+            day: 1,
+            hour: 12,
+            minute: 0,
+            second: 0,
+        }) + day_of_year * 86_400
+    }
+
+    fn day_length(&self, day_of_year: i64) -> i64 {
+        sun::get_events(self.noon(day_of_year), &self.observer).day_length()
+    }
+}
+
+impl<T: Theme> Widget for DaylightChart<'_, T> {
+    type Color = T::Color;
+
+    fn size(&self, available: Size) -> Size {
+        available
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        let year = self.today.year;
+        let days = if is_leap_year(year) { 366 } else { 365 };
+
+        // Days are longest and shortest at the solstices, so those set the
+        // scale, along with 12 hours so the equinoxes are always in view.
+        // Round out to whole hours, and leave room for the marker at the
+        // extremes.
+        let solstice_lengths = [SeasonMarker::JuneSolstice, SeasonMarker::DecemberSolstice]
+            .map(|marker| sun::get_events(seasons::get_instant(year, marker), &self.observer))
+            .map(|events| events.day_length());
+        let longest = solstice_lengths[0]
+            .max(solstice_lengths[1])
+            .max(12 * SECS_PER_HOUR);
+        let shortest = solstice_lengths[0]
+            .min(solstice_lengths[1])
+            .min(12 * SECS_PER_HOUR);
+        let highest = (longest + SECS_PER_HOUR - 1) / SECS_PER_HOUR * SECS_PER_HOUR;
+        let lowest = shortest / SECS_PER_HOUR * SECS_PER_HOUR;
+        let margin = MARKER_DIAMETER as i32 / 2;
+        let top = area.top_left.y + margin;
+        let height = area.size.height as i32 - 1 - 2 * margin;
+        let y_at = |length: i64| {
+            let fraction = (highest - length) as f64 / (highest - lowest).max(1) as f64;
+            top + libm::round(fraction.clamp(0.0, 1.0) * height as f64) as i32
+        };
+        let width = area.size.width as i32;
+        let x_at = |day_of_year: i64| {
+            let fraction = day_of_year as f64 / (days - 1) as f64;
+            area.top_left.x + libm::round(fraction * (width - 1) as f64) as i32
+        };
+        let day_at =
+            |x: i32| libm::round(x as f64 * (days - 1) as f64 / (width - 1).max(1) as f64) as i64;
+
+        let twelve_hours = y_at(12 * SECS_PER_HOUR);
+        let dots = (0..width)
+            .step_by(DOT_SPACING as usize)
+            .map(|x| Point::new(area.top_left.x + x, twelve_hours));
+        target.draw_iter(dots.map(|point| Pixel(point, self.theme.muted())))?;
+
+        for marker in [
+            SeasonMarker::MarchEquinox,
+            SeasonMarker::JuneSolstice,
+            SeasonMarker::SeptemberEquinox,
+            SeasonMarker::DecemberSolstice,
+        ] {
+            let date = self.time_zone.to_local(seasons::get_instant(year, marker));
+            let x = x_at(date.day_of_year() as i64 - 1);
+            let dots = (0..area.size.height as i32)
+                .step_by(DOT_SPACING as usize)
+                .map(|y| Point::new(x, area.top_left.y + y));
+            target.draw_iter(dots.map(|point| Pixel(point, self.theme.muted())))?;
+        }
+
+        let line = PrimitiveStyle::with_stroke(self.theme.text(), 1);
+        let mut previous = Point::new(area.top_left.x, y_at(self.day_length(0)));
+        for x in 1..width {
+            let current = Point::new(area.top_left.x + x, y_at(self.day_length(day_at(x))));
+            Line::new(previous, current)
+                .into_styled(line)
+                .draw(target)?;
+            previous = current;
+        }
+
+        let today = self.today.day_of_year() as i64 - 1;
+        let marker = PrimitiveStyleBuilder::new()
+            .fill_color(self.theme.accent())
+            .stroke_color(self.theme.text())
+            .stroke_width(1)
+            .build();
+        Circle::with_center(
+            Point::new(x_at(today), y_at(self.day_length(today))),
+            MARKER_DIAMETER,
+        )
+        .into_styled(marker)
+        .draw(target)
+    }
+}
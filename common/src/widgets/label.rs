@@ -0,0 +1,59 @@
+use embedded_graphics::{
+    mono_font::MonoTextStyle,
+    prelude::*,
+    primitives::Rectangle,
+    text::{Alignment, Baseline, Text, TextStyle, TextStyleBuilder},
+};
+
+use crate::widgets::Widget;
+
+/// A block of text, with each line centered, drawn in the middle of its area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Label<'a, Color> {
+    pub text: &'a str,
+    pub style: MonoTextStyle<'a, Color>,
+}
+
+impl<'a, Color: PixelColor> Label<'a, Color> {
+    pub fn new(text: &'a str, style: MonoTextStyle<'a, Color>) -> Self {
+        Self { text, style }
+    }
+
+    fn text_at(&self, position: Point) -> Text<'a, MonoTextStyle<'a, Color>> {
+        Text::with_text_style(self.text, position, self.style, TEXT_STYLE)
+    }
+}
+
+const TEXT_STYLE: TextStyle = TextStyleBuilder::new()
+    .alignment(Alignment::Center)
+    .baseline(Baseline::Top)
+    .build();
+
+impl<Color: PixelColor> Widget for Label<'_, Color> {
+    type Color = Color;
+
+    fn size(&self, available: Size) -> Size {
+        if self.text.is_empty() {
+            return Size::zero();
+        }
+        self.text_at(Point::zero())
+            .bounding_box()
+            .size
+            .component_min(available)
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        if self.text.is_empty() {
+            return Ok(());
+        }
+        let height = self.text_at(Point::zero()).bounding_box().size.height;
+        let top = area.top_left.y + (area.size.height as i32 - height as i32) / 2;
+        self.text_at(Point::new(area.center().x, top))
+            .draw(target)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,440 @@
+use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+use crate::widgets::Widget;
+
+/// How a widget takes up space along the direction of the stack it's in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sizing {
+    /// As much as `Widget::size` asks for.
+    Auto,
+    /// Exactly this many pixels.
+    Fixed(u32),
+    /// A share of whatever the other widgets leave, in proportion to this
+    /// weight against those of the other flexible widgets. A weight of 0
+    /// gets no share, so the widget isn't drawn.
+    Flex(u32),
+}
+
+/// Where a widget goes in space bigger than it needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+    /// Grow to fill the space.
+    Stretch,
+}
+
+impl Align {
+    /// Places something `wanted` pixels long in `available` pixels, as
+    /// (offset, length).
+    fn place(&self, wanted: u32, available: u32) -> (u32, u32) {
+        let wanted = wanted.min(available);
+        match self {
+            Align::Start => (0, wanted),
+            Align::Center => ((available - wanted) / 2, wanted),
+            Align::End => (available - wanted, wanted),
+            Align::Stretch => (0, available),
+        }
+    }
+}
+
+/// The direction a stack runs in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+impl Axis {
+    /// Length of `size` along this axis.
+    fn main(&self, size: Size) -> u32 {
+        match self {
+            Axis::Horizontal => size.width,
+            Axis::Vertical => size.height,
+        }
+    }
+
+    /// Length of `size` across this axis.
+    fn cross(&self, size: Size) -> u32 {
+        match self {
+            Axis::Horizontal => size.height,
+            Axis::Vertical => size.width,
+        }
+    }
+
+    fn size(&self, main: u32, cross: u32) -> Size {
+        match self {
+            Axis::Horizontal => Size::new(main, cross),
+            Axis::Vertical => Size::new(cross, main),
+        }
+    }
+
+    fn point(&self, main: u32, cross: u32) -> Point {
+        match self {
+            Axis::Horizontal => Point::new(main as i32, cross as i32),
+            Axis::Vertical => Point::new(cross as i32, main as i32),
+        }
+    }
+}
+
+/// Space around a widget, in pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Padding {
+    pub top: u32,
+    pub right: u32,
+    pub bottom: u32,
+    pub left: u32,
+}
+
+impl Padding {
+    pub fn all(padding: u32) -> Self {
+        Self::symmetric(padding, padding)
+    }
+
+    pub fn symmetric(horizontal: u32, vertical: u32) -> Self {
+        Self {
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+            left: horizontal,
+        }
+    }
+
+    fn size(&self) -> Size {
+        Size::new(self.left + self.right, self.top + self.bottom)
+    }
+}
+
+/// A fixed set of widgets of the same color, which a `Stack` lays out. This
+/// is implemented for tuples of up to eight widgets, and arrays of any
+/// length, so there's no need to allocate.
+pub trait Children {
+    type Color: PixelColor;
+
+    fn count(&self) -> usize;
+    fn sizing(&self, index: usize) -> Sizing;
+    fn size(&self, index: usize, available: Size) -> Size;
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        index: usize,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error>;
+}
+
+impl<W: Widget, const N: usize> Children for [W; N] {
+    type Color = W::Color;
+
+    fn count(&self) -> usize {
+        N
+    }
+
+    fn sizing(&self, index: usize) -> Sizing {
+        self[index].sizing()
+    }
+
+    fn size(&self, index: usize, available: Size) -> Size {
+        self[index].size(available)
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        index: usize,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        self[index].draw(target, area)
+    }
+}
+
+macro_rules! impl_children_for_tuple {
+    ($len:literal; $first:ident $first_index:tt $(, $name:ident $index:tt)*) => {
+        impl<$first: Widget $(, $name: Widget<Color = $first::Color>)*> Children
+            for ($first, $($name,)*)
+        {
+            type Color = $first::Color;
+
+            fn count(&self) -> usize {
+                $len
+            }
+
+            fn sizing(&self, index: usize) -> Sizing {
+                match index {
+                    $first_index => self.$first_index.sizing(),
+                    $($index => self.$index.sizing(),)*
+                    _ => panic!("no child {index}"),
+                }
+            }
+
+            fn size(&self, index: usize, available: Size) -> Size {
+                match index {
+                    $first_index => self.$first_index.size(available),
+                    $($index => self.$index.size(available),)*
+                    _ => panic!("no child {index}"),
+                }
+            }
+
+            fn draw<D: DrawTarget<Color = Self::Color>>(
+                &self,
+                index: usize,
+                target: &mut D,
+                area: Rectangle,
+            ) -> Result<(), D::Error> {
+                match index {
+                    $first_index => self.$first_index.draw(target, area),
+                    $($index => self.$index.draw(target, area),)*
+                    _ => panic!("no child {index}"),
+                }
+            }
+        }
+    };
+}
+
+impl_children_for_tuple!(1; W0 0);
+impl_children_for_tuple!(2; W0 0, W1 1);
+impl_children_for_tuple!(3; W0 0, W1 1, W2 2);
+impl_children_for_tuple!(4; W0 0, W1 1, W2 2, W3 3);
+impl_children_for_tuple!(5; W0 0, W1 1, W2 2, W3 3, W4 4);
+impl_children_for_tuple!(6; W0 0, W1 1, W2 2, W3 3, W4 4, W5 5);
+impl_children_for_tuple!(7; W0 0, W1 1, W2 2, W3 3, W4 4, W5 5, W6 6);
+impl_children_for_tuple!(8; W0 0, W1 1, W2 2, W3 3, W4 4, W5 5, W6 6, W7 7);
+
+/// Widgets one after the other, either down or across. Each child gets the
+/// length its `Widget::sizing` asks for along the stack, with flexible ones
+/// sharing what's left, and is placed across the stack by `align`.
+///
+/// Children that take no space, such as empty labels, are skipped, so don't
+/// get gaps either side.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stack<C> {
+    pub axis: Axis,
+    pub children: C,
+    /// Pixels between children.
+    pub gap: u32,
+    /// Where children go across the stack.
+    pub align: Align,
+    /// Where the children as a whole go along the stack, if none of them are
+    /// flexible to fill it.
+    pub justify: Align,
+}
+
+impl<C: Children> Stack<C> {
+    /// Stacks `children` top to bottom, centered horizontally.
+    pub fn vertical(children: C) -> Self {
+        Self::new(Axis::Vertical, children)
+    }
+
+    /// Stacks `children` left to right, centered vertically.
+    pub fn horizontal(children: C) -> Self {
+        Self::new(Axis::Horizontal, children)
+    }
+
+    fn new(axis: Axis, children: C) -> Self {
+        Self {
+            axis,
+            children,
+            gap: 0,
+            align: Align::Center,
+            justify: Align::Start,
+        }
+    }
+
+    pub fn gap(self, gap: u32) -> Self {
+        Self { gap, ..self }
+    }
+
+    pub fn align(self, align: Align) -> Self {
+        Self { align, ..self }
+    }
+
+    pub fn justify(self, justify: Align) -> Self {
+        Self { justify, ..self }
+    }
+
+    /// The length of the child at `index` along the stack, or `None` if it's
+    /// flexible.
+    fn length(&self, index: usize, available: Size) -> Option<u32> {
+        match self.children.sizing(index) {
+            Sizing::Auto => Some(self.axis.main(self.children.size(index, available))),
+            Sizing::Fixed(length) => Some(length),
+            Sizing::Flex(0) => Some(0),
+            Sizing::Flex(_) => None,
+        }
+    }
+
+    /// Sums up the children's lengths along the stack, as (pixels taken by
+    /// the ones that aren't flexible including gaps, total flexible weight).
+    fn measure(&self, available: Size) -> (u32, u32) {
+        let (mut used, mut weights, mut count) = (0, 0, 0);
+        for index in 0..self.children.count() {
+            match (self.length(index, available), self.children.sizing(index)) {
+                (Some(0), _) => continue,
+                (Some(length), _) => used += length,
+                (None, Sizing::Flex(weight)) => weights += weight,
+                (None, _) => unreachable!(),
+            }
+            count += 1;
+        }
+        (used + self.gap * (count as u32).saturating_sub(1), weights)
+    }
+}
+
+impl<C: Children> Widget for Stack<C> {
+    type Color = C::Color;
+
+    fn size(&self, available: Size) -> Size {
+        let (used, weights) = self.measure(available);
+        let main = if weights > 0 {
+            self.axis.main(available)
+        } else {
+            used.min(self.axis.main(available))
+        };
+        let cross = (0..self.children.count())
+            .map(|index| self.axis.cross(self.children.size(index, available)))
+            .max()
+            .unwrap_or(0);
+        self.axis.size(main, cross.min(self.axis.cross(available)))
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        let available = area.size;
+        let (used, weights) = self.measure(available);
+        let spare = self.axis.main(available).saturating_sub(used);
+        let cross_available = self.axis.cross(available);
+
+        let mut position = if weights > 0 {
+            0
+        } else {
+            self.justify.place(used, used + spare).0
+        };
+        let mut weight_before = 0;
+        for index in 0..self.children.count() {
+            let length = match (self.length(index, available), self.children.sizing(index)) {
+                (Some(0), _) => continue,
+                (Some(length), _) => length,
+                (None, Sizing::Flex(weight)) => {
+                    // Share out by running total, so rounding doesn't lose
+                    // pixels
+                    let start = spare * weight_before / weights;
+                    weight_before += weight;
+                    spare * weight_before / weights - start
+                }
+                (None, _) => unreachable!(),
+            };
+            let size = self
+                .children
+                .size(index, self.axis.size(length, cross_available));
+            let (offset, cross) = self.align.place(self.axis.cross(size), cross_available);
+            let child_area = Rectangle::new(
+                area.top_left + self.axis.point(position, offset),
+                self.axis.size(length, cross),
+            );
+            self.children.draw(index, target, child_area)?;
+            position += length + self.gap;
+        }
+        Ok(())
+    }
+}
+
+/// A widget with space around it, returned by `Widget::padded`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Padded<W> {
+    pub widget: W,
+    pub padding: Padding,
+}
+
+impl<W: Widget> Widget for Padded<W> {
+    type Color = W::Color;
+
+    fn size(&self, available: Size) -> Size {
+        let padding = self.padding.size();
+        (self.widget.size(available.saturating_sub(padding)) + padding).component_min(available)
+    }
+
+    fn sizing(&self) -> Sizing {
+        self.widget.sizing()
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        let inner = Rectangle::new(
+            area.top_left + Point::new(self.padding.left as i32, self.padding.top as i32),
+            area.size.saturating_sub(self.padding.size()),
+        );
+        self.widget.draw(target, inner)
+    }
+}
+
+/// A widget placed within whatever area it's given, which it takes all of,
+/// returned by `Widget::aligned`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aligned<W> {
+    pub widget: W,
+    pub horizontal: Align,
+    pub vertical: Align,
+}
+
+impl<W: Widget> Widget for Aligned<W> {
+    type Color = W::Color;
+
+    fn size(&self, available: Size) -> Size {
+        available
+    }
+
+    fn sizing(&self) -> Sizing {
+        self.widget.sizing()
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        let size = self.widget.size(area.size);
+        let (x, width) = self.horizontal.place(size.width, area.size.width);
+        let (y, height) = self.vertical.place(size.height, area.size.height);
+        self.widget.draw(
+            target,
+            Rectangle::new(
+                area.top_left + Point::new(x as i32, y as i32),
+                Size::new(width, height),
+            ),
+        )
+    }
+}
+
+/// A widget with its own `Sizing`, returned by `Widget::fixed` and
+/// `Widget::flex`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WithSizing<W> {
+    pub widget: W,
+    pub sizing: Sizing,
+}
+
+impl<W: Widget> Widget for WithSizing<W> {
+    type Color = W::Color;
+
+    fn size(&self, available: Size) -> Size {
+        self.widget.size(available)
+    }
+
+    fn sizing(&self) -> Sizing {
+        self.sizing
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        self.widget.draw(target, area)
+    }
+}
@@ -0,0 +1,231 @@
+use embedded_graphics::{
+    mono_font::{MonoTextStyle, ascii::FONT_5X8},
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
+};
+
+use crate::calendar::{Observer, moon};
+use crate::datetime::{DateTime, days_in_month};
+use crate::rtclock;
+use crate::theme::Theme;
+use crate::timezone::TimeZone;
+use crate::widgets::{Widget, moon::MoonDisk};
+
+/// Space between a day's number and its moon, and around the pair, in
+/// pixels.
+const CELL_PADDING: i32 = 2;
+
+/// Column headings, for weeks starting on Monday.
+const WEEKDAY_INITIALS: [&str; 7] = ["M", "T", "W", "T", "F", "S", "S"];
+
+/// Grid of the days in the month containing `today`, a week to a row starting
+/// on Monday, with a picture of the moon's phase at local noon on each day.
+/// Days with a new or full moon have their number picked out, and today is
+/// boxed.
+///
+/// Each day's number and moon go side by side if the cells are wide enough,
+/// as in landscape, or one above the other if not.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MonthGrid<'a, T> {
+    /// Today's local date, which picks the month to show.
+    pub today: DateTime,
+    pub time_zone: TimeZone<'a>,
+    /// Which hemisphere the moon is seen from, which decides the side its lit
+    /// part is drawn on.
+    pub observer: Observer,
+    /// New and full moon days' numbers are drawn in the background color on
+    /// a block of the text color, and the box around today in the accent
+    /// color.
+    pub theme: &'a T,
+}
+
+impl<T: Theme> MonthGrid<'_, T> {
+    /// Local noon on `day` of the month, as a timestamp.
+    fn noon(&self, day: u8) -> rtclock::Timestamp {
+        self.time_zone.from_local(&DateTime {
+            day,
+            hour: 12,
+            minute: 0,
+            second: 0,
+            ..self.today
+        })
+    }
+
+    /// Bitmask of the days of the month on which there's a new or full moon,
+    /// with bit n set for day n.
+    fn principal_phase_days(&self) -> u32 {
+        let midnight = |day: u8| {
+            self.time_zone.from_local(&DateTime {
+                day,
+                hour: 0,
+                minute: 0,
+                second: 0,
+                ..self.today
+            })
+        };
+        // The day after the last is the first of next month
+        let start = midnight(1);
+        let end = midnight(days_in_month(self.today.year, self.today.month) + 1);
+        moon::get_next_phases(start - 1)
+            .take_while(|event| event.instant < end)
+            .filter(|event| {
+                matches!(
+                    event.phase,
+                    moon::PrincipalPhase::NewMoon | moon::PrincipalPhase::FullMoon
+                )
+            })
+            .fold(0, |days, event| {
+                days | 1 << self.time_zone.to_local(event.instant).day
+            })
+    }
+
+    /// Draws `day`'s number and moon centered in `cell`.
+    fn draw_day<D: DrawTarget<Color = T::Color>>(
+        &self,
+        target: &mut D,
+        cell: Rectangle,
+        day: u8,
+        is_principal_phase: bool,
+    ) -> Result<(), D::Error> {
+        let mut number_buf = [0u8; 4];
+        let number = format_no_std::show(&mut number_buf, format_args!("{day}")).unwrap();
+        let number_size = Size::new(
+            2 * FONT_5X8.character_size.width,
+            FONT_5X8.character_size.height,
+        );
+
+        // Whichever arrangement leaves room for the larger moon
+        let (width, height) = (cell.size.width as i32, cell.size.height as i32);
+        let (number_width, number_height) = (number_size.width as i32, number_size.height as i32);
+        let beside = (height - 2 * CELL_PADDING).min(width - number_width - 3 * CELL_PADDING);
+        let below = (width - 2 * CELL_PADDING).min(height - number_height - 3 * CELL_PADDING);
+        let diameter = beside.max(below).max(1);
+        let (number_center, moon_top_left) = if beside >= below {
+            let left = cell.top_left.x + (width - number_width - CELL_PADDING - diameter) / 2;
+            let center_y = cell.center().y;
+            (
+                Point::new(left + number_width / 2, center_y),
+                Point::new(left + number_width + CELL_PADDING, center_y - diameter / 2),
+            )
+        } else {
+            let top = cell.top_left.y + (height - number_height - CELL_PADDING - diameter) / 2;
+            let center_x = cell.center().x;
+            (
+                Point::new(center_x, top + number_height / 2),
+                Point::new(center_x - diameter / 2, top + number_height + CELL_PADDING),
+            )
+        };
+
+        let number_color = if is_principal_phase {
+            Rectangle::with_center(number_center, number_size + Size::new(2, 2))
+                .into_styled(PrimitiveStyle::with_fill(self.theme.text()))
+                .draw(target)?;
+            self.theme.background()
+        } else {
+            self.theme.text()
+        };
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+        Text::with_text_style(
+            number,
+            number_center,
+            MonoTextStyle::new(&FONT_5X8, number_color),
+            text_style,
+        )
+        .draw(target)?;
+
+        let noon = self.noon(day);
+        #[cfg(feature = "illumination-table")]
+        let (phase, illumination) = {
+            let phase = moon::get_approximate_phase(noon);
+            (phase, moon::get_illumination_from_phase(phase))
+        };
+        #[cfg(not(feature = "illumination-table"))]
+        let (phase, illumination) = (moon::get_phase(noon), moon::get_illumination(noon));
+        // A waxing moon is lit on the right seen from the northern hemisphere,
+        // and on the left from the southern
+        let lit_on_right = (phase < 0.5) == (self.observer.latitude >= 0.0);
+        let diameter = diameter as u32;
+        MoonDisk {
+            diameter,
+            illumination,
+            bright_limb_tilt: if lit_on_right { 270.0 } else { 90.0 },
+            theme: self.theme,
+        }
+        .draw(
+            target,
+            Rectangle::new(moon_top_left, Size::new(diameter, diameter)),
+        )
+    }
+}
+
+impl<T: Theme> Widget for MonthGrid<'_, T> {
+    type Color = T::Color;
+
+    fn size(&self, available: Size) -> Size {
+        available
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        let first = DateTime {
+            day: 1,
+            ..self.today
+        };
+        let leading_days = first.weekday().number() as u32 - 1;
+        let days = days_in_month(self.today.year, self.today.month);
+        let rows = (leading_days + days as u32).div_ceil(7);
+
+        let heading_height = FONT_5X8.character_size.height + CELL_PADDING as u32;
+        let cell_size = Size::new(
+            area.size.width / 7,
+            area.size.height.saturating_sub(heading_height) / rows,
+        );
+        // Center the grid in any space left over from rounding
+        let grid_width = 7 * cell_size.width;
+        let top_left = area.top_left + Point::new((area.size.width - grid_width) as i32 / 2, 0);
+        let cell_at = |index: u32| {
+            let (column, row) = (index % 7, index / 7);
+            Rectangle::new(
+                top_left
+                    + Point::new(
+                        (column * cell_size.width) as i32,
+                        (heading_height + row * cell_size.height) as i32,
+                    ),
+                cell_size,
+            )
+        };
+
+        let heading_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Top)
+            .build();
+        for (column, initial) in WEEKDAY_INITIALS.iter().enumerate() {
+            let center_x = cell_at(column as u32).center().x;
+            Text::with_text_style(
+                initial,
+                Point::new(center_x, top_left.y),
+                MonoTextStyle::new(&FONT_5X8, self.theme.muted()),
+                heading_style,
+            )
+            .draw(target)?;
+        }
+
+        let principal_phase_days = self.principal_phase_days();
+        for day in 1..=days {
+            let cell = cell_at(leading_days + day as u32 - 1);
+            self.draw_day(target, cell, day, principal_phase_days & 1 << day != 0)?;
+            if day == self.today.day {
+                cell.into_styled(PrimitiveStyle::with_stroke(self.theme.accent(), 1))
+                    .draw(target)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, PrimitiveStyle, Rectangle},
+};
+
+use crate::theme::Theme;
+use crate::widgets::Widget;
+
+/// The moon's disk as it appears in the sky, lit on one side with the
+/// terminator between the lit and dark parts drawn as a half ellipse. It's
+/// drawn in the middle of its area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MoonDisk<'a, T> {
+    pub diameter: u32,
+    /// Illuminated fraction of the disk [0, 1].
+    pub illumination: f64,
+    /// Direction of the bright limb in degrees, measured from straight up
+    /// through the left, as from `moon::get_bright_limb_tilt`.
+    pub bright_limb_tilt: f64,
+    /// The lit part is drawn in the moonlight color, and the outline, which
+    /// keeps the dark part visible, in the text color.
+    pub theme: &'a T,
+}
+
+impl<T: Theme> Widget for MoonDisk<'_, T> {
+    type Color = T::Color;
+
+    fn size(&self, available: Size) -> Size {
+        Size::new(self.diameter, self.diameter).component_min(available)
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        let top_left = area.top_left
+            + Point::new(
+                (area.size.width as i32 - self.diameter as i32) / 2,
+                (area.size.height as i32 - self.diameter as i32) / 2,
+            );
+        let disk = Circle::new(top_left, self.diameter);
+        let radius = self.diameter as f64 / 2.0;
+
+        // Unit vector towards the bright limb, with y pointing down the screen
+        let tilt = self.bright_limb_tilt.to_radians();
+        let (towards_x, towards_y) = (-libm::sin(tilt), -libm::cos(tilt));
+        // Where the terminator crosses the line through the bright limb, from
+        // -1 (on the dark limb, so all lit) to 1 (on the bright limb)
+        let terminator = 1.0 - 2.0 * self.illumination.clamp(0.0, 1.0);
+
+        let is_lit = |point: &Point| {
+            // Relative to the centre, in radii, sampling the pixel's centre
+            let x = ((point.x - top_left.x) as f64 + 0.5) / radius - 1.0;
+            let y = ((point.y - top_left.y) as f64 + 0.5) / radius - 1.0;
+            let along = x * towards_x + y * towards_y;
+            let across = y * towards_x - x * towards_y;
+            along >= terminator * libm::sqrt((1.0 - across * across).max(0.0))
+        };
+        disk.into_styled(PrimitiveStyle::with_stroke(self.theme.text(), 1))
+            .draw(target)?;
+        target.draw_iter(
+            disk.points()
+                .filter(is_lit)
+                .map(|point| Pixel(point, self.theme.moonlight())),
+        )
+    }
+}
@@ -0,0 +1,36 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+
+use crate::theme::Theme;
+use crate::widgets::Widget;
+
+/// An outlined bar, filled from the left by `progress` [0, 1] in the text
+/// color, which fills whatever area it's given.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProgressBar<'a, T> {
+    pub progress: f64,
+    pub theme: &'a T,
+}
+
+impl<T: Theme> Widget for ProgressBar<'_, T> {
+    type Color = T::Color;
+
+    fn size(&self, available: Size) -> Size {
+        available
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        area.into_styled(PrimitiveStyle::with_stroke(self.theme.text(), 1))
+            .draw(target)?;
+        let filled = libm::round(area.size.width as f64 * self.progress.clamp(0.0, 1.0)) as u32;
+        Rectangle::new(area.top_left, Size::new(filled, area.size.height))
+            .into_styled(PrimitiveStyle::with_fill(self.theme.text()))
+            .draw(target)
+    }
+}
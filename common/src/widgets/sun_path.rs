@@ -0,0 +1,134 @@
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Circle, Line, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle},
+};
+
+use crate::calendar::{
+    Observer, sun,
+    twilight::{ASTRONOMICAL_ALTITUDE, CIVIL_ALTITUDE, NAUTICAL_ALTITUDE},
+};
+use crate::rtclock;
+use crate::theme::Theme;
+use crate::widgets::Widget;
+
+/// How far tick marks stick out either side of the horizon, in pixels.
+const TICK_LENGTH: i32 = 3;
+
+/// Diameter of the marker for the sun's current position, in pixels.
+const MARKER_DIAMETER: u32 = 7;
+
+/// The twilight bands below the horizon, as (upper, lower) altitudes, with
+/// how many pixels apart their shading dots are. Darker twilight gets sparser
+/// shading.
+const TWILIGHT_BANDS: [(f64, f64, i32); 3] = [
+    (0.0, CIVIL_ALTITUDE, 2),
+    (CIVIL_ALTITUDE, NAUTICAL_ALTITUDE, 3),
+    (NAUTICAL_ALTITUDE, ASTRONOMICAL_ALTITUDE, 4),
+];
+
+/// Plot of the sun's altitude over the day containing `instant`, from solar
+/// midnight to solar midnight, as an arc against the horizon. The twilight
+/// bands below the horizon are shaded, sunrise, noon and sunset have tick
+/// marks, and a marker shows where the sun is at `instant`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SunPath<'a, T> {
+    pub instant: rtclock::Timestamp,
+    pub observer: Observer,
+    /// The path is drawn in the text color over muted twilight shading, and
+    /// the sun's marker is filled with the sunlight color.
+    pub theme: &'a T,
+}
+
+impl<T: Theme> Widget for SunPath<'_, T> {
+    type Color = T::Color;
+
+    fn size(&self, available: Size) -> Size {
+        available
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        let events = sun::get_events(self.instant, &self.observer);
+        let start = events.solar_noon - 43_200;
+        let altitude = |instant: rtclock::Timestamp| {
+            sun::get_horizontal_position(instant, &self.observer).altitude
+        };
+
+        // The sun is highest at noon and lowest at the midnights either side,
+        // so those set the scale, along with the horizon so it's always in
+        // view. Leave room for the marker at the extremes.
+        let highest = altitude(events.solar_noon).max(0.0);
+        let lowest = altitude(start).min(altitude(start + 86_400)).min(0.0);
+        let margin = MARKER_DIAMETER as i32 / 2;
+        let top = area.top_left.y + margin;
+        let height = area.size.height as i32 - 1 - 2 * margin;
+        let y_at = |altitude: f64| {
+            let fraction = (highest - altitude) / (highest - lowest).max(1.0);
+            top + libm::round(fraction * height as f64) as i32
+        };
+        let width = area.size.width as i32;
+        let x_at = |instant: rtclock::Timestamp| {
+            let fraction = (instant - start) as f64 / 86_400.0;
+            area.top_left.x + libm::round(fraction * (width - 1) as f64) as i32
+        };
+        let instant_at = |x: i32| start + 86_400 * x as i64 / (width - 1).max(1) as i64;
+
+        for (upper, lower, spacing) in TWILIGHT_BANDS {
+            let (upper, lower) = (y_at(upper), y_at(lower));
+            let dots = area.points().filter(|point| {
+                (upper..lower).contains(&point.y)
+                    && point.x % spacing == 0
+                    && (point.y + point.x / spacing) % spacing == 0
+            });
+            target.draw_iter(dots.map(|point| Pixel(point, self.theme.muted())))?;
+        }
+
+        let line = PrimitiveStyle::with_stroke(self.theme.text(), 1);
+        let horizon = y_at(0.0);
+        Line::new(
+            Point::new(area.top_left.x, horizon),
+            Point::new(area.top_left.x + width - 1, horizon),
+        )
+        .into_styled(line)
+        .draw(target)?;
+
+        let ticks = [
+            events.daylight.rise(),
+            Some(events.solar_noon),
+            events.daylight.set(),
+        ];
+        for instant in ticks.into_iter().flatten() {
+            let x = x_at(instant);
+            Line::new(
+                Point::new(x, horizon - TICK_LENGTH),
+                Point::new(x, horizon + TICK_LENGTH),
+            )
+            .into_styled(line)
+            .draw(target)?;
+        }
+
+        let mut previous = Point::new(area.top_left.x, y_at(altitude(start)));
+        for x in 1..width {
+            let current = Point::new(area.top_left.x + x, y_at(altitude(instant_at(x))));
+            Line::new(previous, current)
+                .into_styled(line)
+                .draw(target)?;
+            previous = current;
+        }
+
+        let marker = PrimitiveStyleBuilder::new()
+            .fill_color(self.theme.sunlight())
+            .stroke_color(self.theme.text())
+            .stroke_width(1)
+            .build();
+        Circle::with_center(
+            Point::new(x_at(self.instant), y_at(altitude(self.instant))),
+            MARKER_DIAMETER,
+        )
+        .into_styled(marker)
+        .draw(target)
+    }
+}
@@ -0,0 +1,920 @@
+use common::{
+    calendar::{
+        EquatorialPosition, Observer,
+        eclipse::{self, Eclipse, EclipseKind},
+        meteors::{self, Rating, Shower, ShowerActivity},
+        moon::{self, Apsis, PrincipalPhase},
+        planets::{self, Planet},
+        search::{Crossing, Direction, ExtremumKind, Search},
+        seasons::{self, SeasonMarker},
+        sun, twilight,
+    },
+    rtclock::{InstantSecs, Timestamp},
+};
+
+const GREENWICH: Observer = Observer {
+    latitude: 51.4769,
+    longitude: -0.0005,
+    elevation: 0.0,
+};
+
+/// Nov 20, 2025 midnight UTC, which the moon phase used to be computed
+/// relative to.
+const OLD_REFERENCE: Timestamp = Timestamp::from_secs(1_763_596_800);
+
+const JAN_1_1900: Timestamp = Timestamp::from_secs(-2_208_988_800);
+const DEC_31_2100: Timestamp = Timestamp::from_secs(4_133_894_400);
+
+const DAY: i64 = 86_400;
+
+fn assert_within(actual: Timestamp, expected: Timestamp, tolerance_secs: i64) {
+    assert!(
+        (actual - expected).abs() <= tolerance_secs,
+        "{actual:?} is not within {tolerance_secs}s of {expected:?}"
+    );
+}
+
+#[test]
+fn sunrise_noon_and_sunset() {
+    // Published times for London, in UTC, as (midday, rise, noon, set)
+    let cases = [
+        // Jun 21, 2025: 04:43, 13:02 and 21:21 BST
+        (1_750_507_200, 1_750_477_380, 1_750_507_320, 1_750_537_260),
+        // Dec 21, 2025: 08:04, 11:58 and 15:53 GMT
+        (1_766_318_400, 1_766_304_240, 1_766_318_280, 1_766_332_380),
+    ];
+
+    for (day, rise, noon, set) in cases {
+        let events = sun::get_events(Timestamp::from_secs(day), &GREENWICH);
+        // Published times are rounded to the minute
+        assert_within(
+            events.daylight.rise().unwrap(),
+            Timestamp::from_secs(rise),
+            90,
+        );
+        assert_within(events.solar_noon, Timestamp::from_secs(noon), 90);
+        assert_within(
+            events.daylight.set().unwrap(),
+            Timestamp::from_secs(set),
+            90,
+        );
+    }
+}
+
+#[test]
+fn phase_at_new_and_full_moons() {
+    // Nov 5, 2025 13:19 UTC
+    let full = moon::get_phase(Timestamp::from_secs(1_762_348_740));
+    assert!((full - 0.5).abs() < 1e-3, "phase {full} at full moon");
+
+    // Nov 20, 2025 06:47 UTC
+    let new = moon::get_phase(Timestamp::from_secs(1_763_621_220));
+    let error = (new + 0.5).rem_euclid(1.0) - 0.5;
+    assert!(error.abs() < 1e-3, "phase {new} at new moon");
+}
+
+#[test]
+fn next_phases() {
+    // Meeus, "Astronomical Algorithms", example 49.a: Feb 18, 1977 03:37:42 TD,
+    // which is 03:36:54 UTC
+    let event = moon::get_next_phases(Timestamp::from_secs(224_985_600))
+        .next()
+        .unwrap();
+    assert_eq!(event.phase, PrincipalPhase::NewMoon);
+    assert_within(event.instant, Timestamp::from_secs(225_085_014), 60);
+
+    // Published phases after Nov 1, 2025, in UTC
+    let expected = [
+        // Nov 5 13:19
+        (PrincipalPhase::FullMoon, 1_762_348_740),
+        // Nov 12 05:28
+        (PrincipalPhase::LastQuarter, 1_762_925_280),
+        // Nov 20 06:47
+        (PrincipalPhase::NewMoon, 1_763_621_220),
+        // Nov 28 06:59
+        (PrincipalPhase::FirstQuarter, 1_764_313_140),
+    ];
+    let events = moon::get_next_phases(Timestamp::from_secs(1_761_955_200));
+    for (event, (phase, instant)) in events.zip(expected) {
+        assert_eq!(event.phase, phase);
+        // Published times are rounded to the minute
+        assert_within(event.instant, Timestamp::from_secs(instant), 90);
+    }
+}
+
+#[test]
+fn principal_phases_either_side_of_reference() {
+    let cases = [
+        // Jan 1, 1900 13:52 UTC
+        (
+            Timestamp::from_secs(-2_208_938_880),
+            PrincipalPhase::NewMoon,
+        ),
+        // Jan 7, 1970 20:35 UTC
+        (Timestamp::from_secs(592_500), PrincipalPhase::NewMoon),
+        // Feb 18, 1977 03:36:54 UTC (Meeus example 49.a)
+        (Timestamp::from_secs(225_085_014), PrincipalPhase::NewMoon),
+        // Jan 21, 2000 04:40 UTC
+        (Timestamp::from_secs(948_429_600), PrincipalPhase::FullMoon),
+        // Nov 20, 2025 06:47 UTC
+        (Timestamp::from_secs(1_763_621_220), PrincipalPhase::NewMoon),
+        // Dec 20, 2025 01:43 UTC
+        (Timestamp::from_secs(1_766_194_980), PrincipalPhase::NewMoon),
+    ];
+
+    for (expected, phase) in cases {
+        let event = moon::get_next_phases(expected - DAY).next().unwrap();
+        assert_eq!(event.phase, phase);
+        assert_within(event.instant, expected, 180);
+    }
+}
+
+#[test]
+fn phase_is_consistent_from_1900_to_2100() {
+    let mut timestamp = JAN_1_1900;
+    while timestamp < DEC_31_2100 {
+        let phase = moon::get_phase(timestamp);
+        assert!(
+            (0.0..1.0).contains(&phase),
+            "phase {phase} at {timestamp:?}"
+        );
+
+        let event = moon::get_next_phases(timestamp).next().unwrap();
+        assert!(event.instant > timestamp);
+        assert!(event.instant - timestamp <= 8 * DAY);
+
+        let expected_phase = match event.phase {
+            PrincipalPhase::NewMoon => 0.0,
+            PrincipalPhase::FirstQuarter => 0.25,
+            PrincipalPhase::FullMoon => 0.5,
+            PrincipalPhase::LastQuarter => 0.75,
+        };
+        let phase_at_event = moon::get_phase(event.instant);
+        let error = (phase_at_event - expected_phase + 0.5).rem_euclid(1.0) - 0.5;
+        assert!(
+            error.abs() < 1e-3,
+            "phase {phase_at_event} at {:?} {:?}",
+            event.phase,
+            event.instant
+        );
+
+        // Step by an awkward number of days so every phase gets sampled
+        timestamp = timestamp + 97 * DAY + 12_345;
+    }
+}
+
+#[test]
+fn approximate_phase_and_illumination() {
+    let mut timestamp = JAN_1_1900;
+    while timestamp < DEC_31_2100 {
+        let phase = moon::get_phase(timestamp);
+        let approximate_phase = moon::get_approximate_phase(timestamp);
+        let error = (approximate_phase - phase + 0.5).rem_euclid(1.0) - 0.5;
+        assert!(
+            error.abs() < 0.6 / 360.0,
+            "phase {approximate_phase}, expected {phase} at {timestamp:?}"
+        );
+
+        let illumination = moon::get_illumination(timestamp);
+        let table_illumination = moon::get_illumination_from_phase(approximate_phase);
+        assert!(
+            (table_illumination - illumination).abs() < 0.005,
+            "illumination {table_illumination}, expected {illumination} at {timestamp:?}"
+        );
+
+        timestamp = timestamp + 13 * DAY + 4321;
+    }
+}
+
+#[test]
+fn phase_crosses_old_reference() {
+    let before = moon::get_phase(OLD_REFERENCE - 3600);
+    let after = moon::get_phase(OLD_REFERENCE + 3600);
+    // The new moon was a few hours after the old reference
+    assert!(before > 0.98 && after > 0.98);
+    assert!(after > before);
+}
+
+#[test]
+fn sun_horizontal_position() {
+    // At the June solstice noon the sun is due south, 90° - 51.48° + 23.44°
+    // up
+    let events = sun::get_events(Timestamp::from_secs(1_750_420_800), &GREENWICH);
+    let position = sun::get_horizontal_position(events.solar_noon, &GREENWICH);
+    assert!((position.azimuth - 180.0).abs() < 0.1, "{position:?}");
+    assert!((position.altitude - 61.96).abs() < 0.05, "{position:?}");
+
+    // At sunrise the centre is still a little below the horizon
+    let sunrise = events.daylight.rise().unwrap();
+    let position = sun::get_horizontal_position(sunrise, &GREENWICH);
+    assert!((position.altitude - -0.83).abs() < 0.01, "{position:?}");
+    assert!((40.0..60.0).contains(&position.azimuth), "{position:?}");
+}
+
+#[test]
+fn day_length() {
+    // Jun 21, 2025 and Dec 21, 2025, the longest and shortest days
+    let events = sun::get_events(Timestamp::from_secs(1_750_507_200), &GREENWICH);
+    assert!((events.day_length() - 59_906).abs() < 60, "{events:?}");
+    let events = sun::get_events(Timestamp::from_secs(1_766_318_400), &GREENWICH);
+    assert!((events.day_length() - 28_182).abs() < 60, "{events:?}");
+
+    // Polar day and night at Longyearbyen, Svalbard
+    let longyearbyen = Observer {
+        latitude: 78.22,
+        longitude: 15.65,
+        elevation: 0.0,
+    };
+    let events = sun::get_events(Timestamp::from_secs(1_750_507_200), &longyearbyen);
+    assert_eq!(events.day_length(), 86_400);
+    let events = sun::get_events(Timestamp::from_secs(1_766_318_400), &longyearbyen);
+    assert_eq!(events.day_length(), 0);
+}
+
+#[test]
+fn twilight_dawn_and_dusk() {
+    // Dawn and dusk at Greenwich, in UTC, as given by NOAA's solar calculator
+    let twilight = twilight::get_twilight(Timestamp::from_secs(1_766_318_400), &GREENWICH);
+    let cases = [
+        // Dec 21, 2025: civil 07:23 to 16:33
+        (twilight.civil, 1_766_301_780, 1_766_334_780),
+        // Nautical 06:40 to 17:17
+        (twilight.nautical, 1_766_299_200, 1_766_337_420),
+        // Astronomical 05:59 to 17:57
+        (twilight.astronomical, 1_766_296_740, 1_766_339_820),
+    ];
+    let twilight = twilight::get_twilight(Timestamp::from_secs(1_750_507_200), &GREENWICH);
+    let cases = cases.into_iter().chain([
+        // Jun 21, 2025: civil 02:55 to 21:09
+        (twilight.civil, 1_750_474_500, 1_750_540_140),
+        // Nautical 01:41 to 22:23
+        (twilight.nautical, 1_750_470_060, 1_750_544_580),
+    ]);
+
+    for (crossing, dawn, dusk) in cases {
+        // Published times are rounded to the minute
+        assert_within(crossing.rise().unwrap(), Timestamp::from_secs(dawn), 90);
+        assert_within(crossing.set().unwrap(), Timestamp::from_secs(dusk), 90);
+    }
+
+    // At midsummer the sun is never more than 16° below London's horizon
+    assert_eq!(twilight.astronomical, sun::Crossing::AlwaysAbove);
+}
+
+#[test]
+fn golden_and_blue_hours() {
+    let altitude = |instant| sun::get_horizontal_position(instant, &GREENWICH).altitude;
+    let assert_covers = |interval: twilight::Interval, from: f64, to: f64| {
+        let (start, end) = (altitude(interval.start), altitude(interval.end));
+        let middle = altitude(interval.start + (interval.end - interval.start) / 2);
+        assert!((start - from).abs() < 0.05, "{start}° at {interval:?}");
+        assert!((end - to).abs() < 0.05, "{end}° at {interval:?}");
+        assert!(middle > from.min(to) && middle < from.max(to));
+    };
+
+    // Jun 21 and Dec 21, 2025
+    for day in [1_750_507_200, 1_766_318_400] {
+        let twilight = twilight::get_twilight(Timestamp::from_secs(day), &GREENWICH);
+        let golden_hour = twilight.golden_hour;
+        let blue_hour = twilight.blue_hour;
+
+        // The sun rises through the blue hour, then the golden hour, and
+        // sets through them the other way round
+        assert_covers(blue_hour.morning.unwrap(), -6.0, -4.0);
+        assert_covers(golden_hour.morning.unwrap(), -4.0, 6.0);
+        assert_covers(golden_hour.evening.unwrap(), 6.0, -4.0);
+        assert_covers(blue_hour.evening.unwrap(), -4.0, -6.0);
+        assert_eq!(
+            blue_hour.morning.unwrap().end,
+            golden_hour.morning.unwrap().start
+        );
+        assert_eq!(
+            golden_hour.evening.unwrap().end,
+            blue_hour.evening.unwrap().start
+        );
+        assert_eq!(
+            blue_hour.morning.unwrap().start,
+            twilight.civil.rise().unwrap()
+        );
+    }
+}
+
+#[test]
+fn polar_twilight() {
+    let tromso = Observer {
+        latitude: 69.65,
+        longitude: 18.96,
+        elevation: 0.0,
+    };
+    let longyearbyen = Observer {
+        latitude: 78.22,
+        longitude: 15.65,
+        elevation: 0.0,
+    };
+
+    // Midnight sun at Tromsø on Jun 21, 2025, so it never gets dark enough for
+    // any twilight, or for the blue hour
+    let twilight = twilight::get_twilight(Timestamp::from_secs(1_750_507_200), &tromso);
+    assert_eq!(twilight.civil, sun::Crossing::AlwaysAbove);
+    assert_eq!(twilight.nautical, sun::Crossing::AlwaysAbove);
+    assert_eq!(twilight.astronomical, sun::Crossing::AlwaysAbove);
+    assert_eq!(twilight.blue_hour.morning, None);
+    assert_eq!(twilight.blue_hour.evening, None);
+
+    // Polar night at Tromsø on Dec 21, 2025, which still has civil twilight
+    // around noon
+    let twilight = twilight::get_twilight(Timestamp::from_secs(1_766_318_400), &tromso);
+    assert!(matches!(twilight.civil, sun::Crossing::RiseSet { .. }));
+
+    // Further north at Longyearbyen the sun stays more than 6° below the
+    // horizon, so there's only nautical twilight, and no golden or blue hour
+    let twilight = twilight::get_twilight(Timestamp::from_secs(1_766_318_400), &longyearbyen);
+    assert_eq!(twilight.civil, sun::Crossing::AlwaysBelow);
+    assert!(matches!(twilight.nautical, sun::Crossing::RiseSet { .. }));
+    assert!(matches!(
+        twilight.astronomical,
+        sun::Crossing::RiseSet { .. }
+    ));
+    assert_eq!(twilight.golden_hour.morning, None);
+    assert_eq!(twilight.blue_hour.evening, None);
+}
+
+#[test]
+fn sunrise_before_unix_epoch() {
+    // Jun 21, 1900
+    let events = sun::get_events(Timestamp::from_secs(-2_194_171_200), &GREENWICH);
+    assert_within(
+        events.daylight.rise().unwrap(),
+        Timestamp::from_secs(-2_194_201_020),
+        120,
+    );
+
+    // Dec 21, 1900
+    let events = sun::get_events(Timestamp::from_secs(-2_178_360_000), &GREENWICH);
+    assert_within(
+        events.daylight.set().unwrap(),
+        Timestamp::from_secs(-2_178_346_020),
+        120,
+    );
+}
+
+#[test]
+fn unset_clock_near_epoch() {
+    let instant = InstantSecs::from_ticks(0);
+
+    let phase = moon::get_phase(instant);
+    assert!((0.0..1.0).contains(&phase));
+    assert!(moon::get_next_phases(instant).next().unwrap().instant > Timestamp::from(instant));
+
+    // Greenwich is just west of the prime meridian, so the epoch is still on
+    // Dec 31, 1969 there and the day's events are all before it
+    let events = sun::get_events(instant, &GREENWICH);
+    assert!(events.daylight.rise().unwrap() < Timestamp::from(instant));
+    moon::get_events(instant, &GREENWICH);
+    twilight::get_twilight(instant, &GREENWICH);
+}
+
+#[test]
+fn moonrise_transit_and_moonset() {
+    // Moonrise, transit and moonset at Greenwich in Nov 2025, in UTC, to the
+    // minute. These were checked against Paul Schlyter's method for the moon's
+    // position, which is independent of the series used here, and agree to
+    // within 30 seconds.
+    let cases = [
+        // Nov 1: sets 01:06, rises 14:53, transits 20:33
+        (
+            1_761_998_400,
+            Some(1_762_008_780),
+            Some(1_762_029_180),
+            Some(1_761_959_160),
+        ),
+        // Nov 5: sets 07:01, rises 15:54, and next transits just after midnight
+        (
+            1_762_344_000,
+            Some(1_762_358_040),
+            None,
+            Some(1_762_326_060),
+        ),
+        // Nov 13: transits 06:54, sets 13:54, and next rises just after midnight
+        (
+            1_763_035_200,
+            None,
+            Some(1_763_016_840),
+            Some(1_763_042_040),
+        ),
+        // Nov 20: rises 08:04, transits 11:51, sets 15:30
+        (
+            1_763_640_000,
+            Some(1_763_625_840),
+            Some(1_763_639_460),
+            Some(1_763_652_600),
+        ),
+        // Nov 25: rises 12:09, transits 16:05, sets 20:10
+        (
+            1_764_072_000,
+            Some(1_764_072_540),
+            Some(1_764_086_700),
+            Some(1_764_101_400),
+        ),
+        // Nov 28: rises 12:58, transits 18:24, and next sets just after midnight
+        (
+            1_764_331_200,
+            Some(1_764_334_680),
+            Some(1_764_354_240),
+            None,
+        ),
+    ];
+
+    for (day, rise, transit, set) in cases {
+        let events = moon::get_events(Timestamp::from_secs(day), &GREENWICH);
+        for (actual, expected) in [
+            (events.rise, rise),
+            (events.transit, transit),
+            (events.set, set),
+        ] {
+            match (actual, expected) {
+                (Some(actual), Some(expected)) => {
+                    assert_within(actual, Timestamp::from_secs(expected), 90)
+                }
+                (actual, expected) => assert_eq!(
+                    actual,
+                    expected.map(Timestamp::from_secs),
+                    "{events:?} on day {day}"
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn days_without_moonrise_or_moonset() {
+    // Nov 13, 2025: the moon rose at 23:35 the day before, and next rises at
+    // 00:51 the day after
+    let events = moon::get_events(Timestamp::from_secs(1_763_035_200), &GREENWICH);
+    assert_eq!(events.rise, None);
+    assert!(events.set.is_some());
+
+    // Nov 28, 2025: the moon set at 22:46 the day before, and next sets at
+    // 00:06 the day after
+    let events = moon::get_events(Timestamp::from_secs(1_764_331_200), &GREENWICH);
+    assert_eq!(events.set, None);
+    assert!(events.rise.is_some());
+
+    // Neighbouring days have both
+    for day in [1_762_948_800, 1_763_121_600, 1_764_244_800, 1_764_417_600] {
+        let events = moon::get_events(Timestamp::from_secs(day), &GREENWICH);
+        assert!(events.rise.is_some() && events.set.is_some(), "{events:?}");
+    }
+}
+
+#[test]
+fn perigee_and_apogee() {
+    // Jan 8, 2025 00:01 UTC, then Jan 21, 2025 04:55 UTC
+    let mut apsides = moon::get_next_apsides(Timestamp::from_secs(1_735_689_600));
+    let perigee = apsides.next().unwrap();
+    assert_eq!(perigee.apsis, Apsis::Perigee);
+    assert_within(perigee.instant, Timestamp::from_secs(1_736_294_460), 600);
+    assert!((perigee.distance - 370_174.0).abs() < 10.0);
+
+    let apogee = apsides.next().unwrap();
+    assert_eq!(apogee.apsis, Apsis::Apogee);
+    assert_within(apogee.instant, Timestamp::from_secs(1_737_435_300), 600);
+    assert!((apogee.distance - 404_298.0).abs() < 10.0);
+
+    for event in apsides.take(50) {
+        assert!((356_000.0..407_000.0).contains(&event.distance));
+        let diameter = moon::get_angular_diameter(event.instant);
+        assert!((0.48..0.57).contains(&diameter), "{diameter}");
+    }
+}
+
+#[test]
+fn supermoons() {
+    // The full moon of Nov 5, 2025 was the closest of the year, and the new
+    // moon after it the furthest
+    let mut phases = moon::get_next_phases(Timestamp::from_secs(1_762_300_000));
+    let full = phases.next().unwrap();
+    assert_eq!(full.phase, PrincipalPhase::FullMoon);
+    assert_eq!(full.apsis(), Some(Apsis::Perigee));
+    assert_eq!(full.label(), "full supermoon");
+
+    let quarter = phases.next().unwrap();
+    assert_eq!(quarter.apsis(), None);
+    assert_eq!(quarter.label(), "last quarter");
+
+    let new = phases.next().unwrap();
+    assert_eq!(new.apsis(), Some(Apsis::Apogee));
+    assert_eq!(new.label(), "new micromoon");
+}
+
+#[test]
+fn moon_bright_limb() {
+    // Apr 12, 1992 00:00 TD (Meeus example 48.a)
+    let angle = moon::get_bright_limb_angle(Timestamp::from_secs(703_036_741));
+    assert!((angle - 285.0).abs() < 0.1, "{angle}");
+
+    // The crescent on the evening of Oct 24, 2025 was lit from below and to
+    // the right in London...
+    let tilt = moon::get_bright_limb_tilt(Timestamp::from_secs(1_761_325_200), &GREENWICH);
+    assert!((180.0..270.0).contains(&tilt), "{tilt}");
+    // ...but from below and to the left in Sydney
+    let sydney = Observer {
+        latitude: -33.86,
+        longitude: 151.21,
+        elevation: 0.0,
+    };
+    let tilt = moon::get_bright_limb_tilt(Timestamp::from_secs(1_761_294_600), &sydney);
+    assert!((90.0..180.0).contains(&tilt), "{tilt}");
+}
+
+#[test]
+fn equinoxes_and_solstices() {
+    let cases = [
+        // Mar 20, 2024 03:06 UTC
+        (2024, SeasonMarker::MarchEquinox, 1_710_903_960),
+        // Jun 20, 2024 20:51 UTC
+        (2024, SeasonMarker::JuneSolstice, 1_718_916_660),
+        // Sep 22, 2024 12:44 UTC
+        (2024, SeasonMarker::SeptemberEquinox, 1_727_009_040),
+        // Dec 21, 2024 09:21 UTC
+        (2024, SeasonMarker::DecemberSolstice, 1_734_772_860),
+        // Jun 21, 1962 21:24 UTC (Meeus example 27.a)
+        (1962, SeasonMarker::JuneSolstice, -237_609_360),
+    ];
+
+    for (year, marker, expected) in cases {
+        assert_within(
+            seasons::get_instant(year, marker),
+            Timestamp::from_secs(expected),
+            90,
+        );
+    }
+}
+
+#[test]
+fn cross_quarter_days_are_between_quarters() {
+    let mut events = seasons::get_next_events(JAN_1_1900);
+    let mut previous = events.next().unwrap();
+    assert_eq!(previous.marker, SeasonMarker::FebruaryCrossQuarter);
+    for event in events.take(8 * 200) {
+        let gap = event.instant - previous.instant;
+        assert!(
+            (44 * DAY..=48 * DAY).contains(&gap),
+            "{event:?} is {gap}s after {previous:?}"
+        );
+        previous = event;
+    }
+
+    let year = seasons::get_instant(2025, SeasonMarker::FebruaryCrossQuarter);
+    assert!(year > seasons::get_instant(2024, SeasonMarker::DecemberSolstice));
+    assert!(year < seasons::get_instant(2025, SeasonMarker::MarchEquinox));
+}
+
+#[test]
+fn season_progress() {
+    let equinox = seasons::get_instant(2025, SeasonMarker::SeptemberEquinox);
+    let solstice = seasons::get_instant(2025, SeasonMarker::DecemberSolstice);
+
+    let season = seasons::get_season(equinox + 3600);
+    assert_eq!(season.start.instant, equinox);
+    assert_eq!(season.end.instant, solstice);
+    assert_eq!(season.label(&GREENWICH), "autumn");
+    assert!(season.progress(equinox + 3600) < 0.001);
+
+    let season = seasons::get_season(equinox - 3600);
+    assert_eq!(season.end.instant, equinox);
+    assert!(season.progress(equinox - 3600) > 0.999);
+
+    let sydney = Observer {
+        latitude: -33.86,
+        longitude: 151.21,
+        elevation: 0.0,
+    };
+    assert_eq!(season.label(&sydney), "winter");
+}
+
+#[test]
+fn eclipses_2024_to_2026() {
+    use EclipseKind::*;
+    // Greatest eclipse times from NASA's eclipse catalogues
+    let expected = [
+        (1_711_350_780, Penumbral, false),
+        (1_712_600_220, Total, true),
+        (1_726_627_440, Partial, false),
+        (1_727_894_700, Annular, true),
+        (1_741_935_540, Total, false),
+        (1_743_245_220, Partial, true),
+        (1_757_268_660, Total, false),
+        (1_758_483_720, Partial, true),
+        (1_771_330_320, Annular, true),
+        (1_772_537_580, Total, false),
+        (1_786_556_760, Total, true),
+        (1_787_890_380, Partial, false),
+    ];
+
+    let eclipses = eclipse::get_next_eclipses(Timestamp::from_secs(1_704_067_200));
+    for (eclipse, (maximum, kind, is_solar)) in eclipses.zip(expected) {
+        assert_eq!(eclipse.kind(), kind, "{eclipse:?}");
+        assert_eq!(matches!(eclipse, Eclipse::Solar(_)), is_solar);
+        assert_within(eclipse.maximum(), Timestamp::from_secs(maximum), 300);
+    }
+}
+
+#[test]
+fn lunar_eclipse_phases() {
+    // Mar 14, 2025, with an umbral magnitude of 1.178
+    let Some(Eclipse::Lunar(eclipse)) =
+        eclipse::get_next_eclipses(Timestamp::from_secs(1_741_900_000)).next()
+    else {
+        panic!("expected a lunar eclipse");
+    };
+    assert!((eclipse.umbral_magnitude - 1.178).abs() < 0.01);
+    let total = eclipse.total.unwrap();
+    let partial = eclipse.partial.unwrap();
+    assert!(eclipse.penumbral.start < partial.start && partial.start < total.start);
+    assert!(total.start < eclipse.maximum && eclipse.maximum < total.end);
+    assert!(total.end < partial.end && partial.end < eclipse.penumbral.end);
+    // Totality lasted about 65 minutes
+    assert!((total.end - total.start - 65 * 60).abs() < 180);
+}
+
+#[test]
+fn solar_eclipse_local_circumstances() {
+    let Some(Eclipse::Solar(eclipse)) =
+        eclipse::get_next_eclipses(Timestamp::from_secs(1_712_000_000)).next()
+    else {
+        panic!("expected a solar eclipse");
+    };
+
+    // Apr 8, 2024 was total in Dallas
+    let dallas = Observer {
+        latitude: 32.78,
+        longitude: -96.80,
+        elevation: 140.0,
+    };
+    let local = eclipse::get_local_circumstances(&eclipse, &dallas).unwrap();
+    assert_within(
+        local.first_contact,
+        Timestamp::from_secs(1_712_596_980),
+        150,
+    );
+    assert_within(local.maximum, Timestamp::from_secs(1_712_601_720), 150);
+    assert_within(local.last_contact, Timestamp::from_secs(1_712_606_580), 150);
+    assert!(local.magnitude > 1.0);
+    assert_eq!(local.obscuration, 1.0);
+
+    // ...but wasn't visible from Greenwich
+    assert_eq!(eclipse::get_local_circumstances(&eclipse, &GREENWICH), None);
+}
+
+#[test]
+fn planet_positions() {
+    // Dec 20, 1992 0h TD (Meeus examples 32.a and 33.a)
+    let instant = Timestamp::from_secs(724_809_539);
+    let heliocentric = planets::get_heliocentric_position(Planet::Venus, instant);
+    assert!((heliocentric.longitude - 26.11428).abs() < 0.001);
+    assert!((heliocentric.latitude - -2.62070).abs() < 0.001);
+    assert!((heliocentric.distance - 0.724603).abs() < 0.00001);
+
+    // 21h04m41.454s, -18°53'16.84"
+    let position = planets::get_position(Planet::Venus, instant);
+    assert!((position.right_ascension - 316.17273).abs() < 0.001);
+    assert!((position.declination - -18.88801).abs() < 0.001);
+}
+
+#[test]
+fn planet_oppositions_and_conjunctions() {
+    let oppositions = [
+        // Dec 8, 2022 05:00 UTC, magnitude -1.9
+        (Planet::Mars, 1_670_475_600, -1.9),
+        // Aug 27, 2023 08:00 UTC, magnitude +0.4
+        (Planet::Saturn, 1_693_123_200, 0.4),
+        // Nov 3, 2023 05:00 UTC, magnitude -2.9
+        (Planet::Jupiter, 1_698_987_600, -2.9),
+    ];
+    for (planet, secs, magnitude) in oppositions {
+        let instant = Timestamp::from_secs(secs);
+        // Passing from the morning sky to the evening sky
+        let before = planets::get_elongation(planet, instant - 3600);
+        let after = planets::get_elongation(planet, instant + 3600);
+        assert!(before < -175.0 && after > 175.0, "{planet:?}");
+        assert!(
+            (planets::get_magnitude(planet, instant) - magnitude).abs() < 0.1,
+            "{planet:?}"
+        );
+    }
+
+    // Venus passed between the earth and sun on Aug 13, 2023 around 11:00 UTC
+    let conjunction = Timestamp::from_secs(1_691_924_400);
+    let before = planets::get_elongation(Planet::Venus, conjunction - 3 * 3600);
+    let after = planets::get_elongation(Planet::Venus, conjunction + 3 * 3600);
+    assert!(before > 0.0 && before < 10.0);
+    assert!(after < 0.0 && after > -10.0);
+}
+
+#[test]
+fn planet_rise_transit_set() {
+    // Venus from Boston on Mar 20, 1988 (Meeus example 15.a)
+    let boston = Observer {
+        latitude: 42.3333,
+        longitude: -71.0833,
+        elevation: 0.0,
+    };
+    let events = planets::get_events(Planet::Venus, Timestamp::from_secs(574_863_900), &boston);
+    assert_within(events.rise.unwrap(), Timestamp::from_secs(574_863_900), 60);
+    assert_within(
+        events.transit.unwrap(),
+        Timestamp::from_secs(574_890_030),
+        60,
+    );
+    // Venus sets after midnight UTC, but before local midnight
+    let set = events.set.unwrap();
+    assert!(set > events.transit.unwrap() && set - events.transit.unwrap() < DAY / 2);
+}
+
+#[test]
+fn planets_visible_tonight() {
+    // Jan 15, 2025, the night before Mars' opposition
+    let noon = Timestamp::from_secs(1_736_942_400);
+    let visible: Vec<_> = planets::get_visible_tonight(noon, &GREENWICH).collect();
+    let planets: Vec<_> = visible.iter().map(|visibility| visibility.planet).collect();
+    assert_eq!(
+        planets,
+        [Planet::Venus, Planet::Mars, Planet::Jupiter, Planet::Saturn]
+    );
+
+    // Venus and Saturn were in the evening sky, Mars was up all night and
+    // Jupiter set before dawn
+    let [venus, mars, jupiter, saturn] = visible[..] else {
+        unreachable!()
+    };
+    assert_eq!(venus.rise, None);
+    assert_within(venus.set.unwrap(), Timestamp::from_secs(1_736_974_020), 300);
+    assert_eq!((mars.rise, mars.set), (None, None));
+    assert_within(
+        jupiter.set.unwrap(),
+        Timestamp::from_secs(1_737_003_840),
+        300,
+    );
+    assert_within(
+        saturn.set.unwrap(),
+        Timestamp::from_secs(1_736_974_560),
+        300,
+    );
+    assert!(venus.magnitude < -4.0 && mars.magnitude < -1.0);
+
+    // Before dawn, it's still the same night
+    let before_dawn = noon + 15 * 3600;
+    assert_eq!(
+        planets::get_visible_tonight(before_dawn, &GREENWICH).count(),
+        4
+    );
+
+    // Nothing is visible in the midnight sun
+    let svalbard = Observer {
+        latitude: 78.2,
+        longitude: 15.6,
+        elevation: 0.0,
+    };
+    let midsummer = Timestamp::from_secs(1_749_988_800);
+    assert_eq!(
+        planets::get_visible_tonight(midsummer, &svalbard).count(),
+        0
+    );
+}
+
+#[test]
+fn meteor_showers() {
+    // The Quadrantids span the new year, peaking on Jan 3, 2025
+    let activity = meteors::get_shower(Timestamp::from_secs(1_735_516_800));
+    assert_eq!(activity.shower.name, "Quadrantids");
+    assert!(activity.active.start < Timestamp::from_secs(1_735_516_800));
+    assert_within(activity.peak, Timestamp::from_secs(1_735_887_600), 3 * 3600);
+
+    // Nothing is active on Feb 1, 2025, so the next is the Lyrids in April
+    let activity = meteors::get_shower(Timestamp::from_secs(1_738_368_000));
+    assert_eq!(activity.shower.name, "Lyrids");
+    assert_within(
+        activity.active.start,
+        Timestamp::from_secs(1_744_588_800),
+        DAY,
+    );
+    assert_within(activity.peak, Timestamp::from_secs(1_745_294_400), 3 * 3600);
+
+    // The Eta Aquariids are also active at the Lyrids' peak, but peak later
+    let activity = meteors::get_shower(Timestamp::from_secs(1_745_280_000));
+    assert_eq!(activity.shower.name, "Lyrids");
+}
+
+#[test]
+fn meteor_shower_moonlight() {
+    // In 2024, the first quarter moon set before the Perseids' radiant got
+    // high...
+    let activity = meteors::get_shower(Timestamp::from_secs(1_723_248_000));
+    assert_eq!(activity.shower.name, "Perseids");
+    let conditions = meteors::get_viewing_conditions(&activity, &GREENWICH);
+    assert_eq!(conditions.rating, Rating::Excellent);
+    assert_eq!(conditions.moonlight, 0.0);
+    assert!(conditions.radiant_altitude > 60.0);
+
+    // ...but in 2025 a waning gibbous moon was up all through the small hours
+    let activity = meteors::get_shower(Timestamp::from_secs(1_754_784_000));
+    assert_eq!(activity.shower.name, "Perseids");
+    let conditions = meteors::get_viewing_conditions(&activity, &GREENWICH);
+    assert_eq!(conditions.rating, Rating::Poor);
+    assert!(conditions.moon_illumination > 0.8);
+
+    // The best time is before dawn on the morning of the peak
+    let best_time = conditions.best_time.unwrap();
+    assert!(best_time < activity.peak && activity.peak - best_time < DAY / 2);
+}
+
+#[test]
+fn meteor_shower_low_radiant() {
+    // The Perseids in 2024 had no moon, but with the radiant moved this far
+    // south it would peak only a degree above Greenwich's horizon
+    let perseids = meteors::get_shower(Timestamp::from_secs(1_723_248_000));
+    let southern_perseids: &'static Shower = Box::leak(Box::new(Shower {
+        radiant: EquatorialPosition {
+            right_ascension: perseids.shower.radiant.right_ascension,
+            declination: -37.5,
+        },
+        ..*perseids.shower
+    }));
+    let activity = ShowerActivity {
+        shower: southern_perseids,
+        ..perseids
+    };
+    let conditions = meteors::get_viewing_conditions(&activity, &GREENWICH);
+    assert_eq!(conditions.moonlight, 0.0);
+    assert!(conditions.radiant_altitude < 1.5, "{conditions:?}");
+    assert!(conditions.score < 0.03, "{conditions:?}");
+    assert_eq!(conditions.rating, Rating::Poor);
+}
+
+#[test]
+fn search_crossings_and_extrema() {
+    let search = Search {
+        step: 3600,
+        tolerance: 1,
+    };
+    // Peaks at 06:00 and bottoms out at 18:00 each day
+    let wave =
+        |instant: Timestamp| (instant.secs() as f64 / DAY as f64 * core::f64::consts::TAU).sin();
+
+    let crossings: Vec<Crossing> = search
+        .crossings(
+            wave,
+            0.5,
+            Timestamp::from_secs(0),
+            Some(Timestamp::from_secs(DAY)),
+        )
+        .collect();
+    assert_eq!(crossings.len(), 2);
+    // sin(x) = 0.5 at 02:00 and 10:00
+    assert_eq!(crossings[0].direction, Direction::Rising);
+    assert_within(crossings[0].instant, Timestamp::from_secs(7200), 1);
+    assert_eq!(crossings[1].direction, Direction::Falling);
+    assert_within(crossings[1].instant, Timestamp::from_secs(36_000), 1);
+
+    // Unbounded, so carries on into the following days
+    let extrema: Vec<_> = search
+        .extrema(wave, Timestamp::from_secs(0), None)
+        .take(4)
+        .collect();
+    let expected = [
+        (ExtremumKind::Maximum, 21_600, 1.0),
+        (ExtremumKind::Minimum, 64_800, -1.0),
+        (ExtremumKind::Maximum, DAY + 21_600, 1.0),
+        (ExtremumKind::Minimum, DAY + 64_800, -1.0),
+    ];
+    for (extremum, (kind, instant, value)) in extrema.iter().zip(expected) {
+        assert_eq!(extremum.kind, kind);
+        assert_within(extremum.instant, Timestamp::from_secs(instant), 2);
+        assert!((extremum.value - value).abs() < 1e-6);
+    }
+
+    // Without a crossing, refining gives the end that comes closer
+    let (midnight, six) = (Timestamp::from_secs(0), Timestamp::from_secs(21_600));
+    assert_eq!(search.refine_crossing(&wave, 2.0, midnight, six), six);
+    assert_eq!(search.refine_crossing(&wave, -2.0, midnight, six), midnight);
+}
+
+#[test]
+#[should_panic(expected = "search step must be positive")]
+fn search_rejects_non_positive_step() {
+    let search = Search {
+        step: 0,
+        tolerance: 1,
+    };
+    let _ = search.crossings(|_| 0.0, 0.5, Timestamp::from_secs(0), None);
+}
+
+#[test]
+fn timestamp_to_instant() {
+    assert_eq!(Timestamp::from_secs(-1).to_instant(), None);
+    assert_eq!(
+        Timestamp::from_secs(1_763_596_800).to_instant(),
+        Some(InstantSecs::from_ticks(1_763_596_800))
+    );
+}
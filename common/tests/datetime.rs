@@ -0,0 +1,100 @@
+use common::{
+    datetime::{DateTime, Weekday, days_in_month, is_leap_year},
+    rtclock::Timestamp,
+};
+
+fn date_time(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+    DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    }
+}
+
+#[test]
+fn known_dates() {
+    let cases = [
+        (0, date_time(1970, 1, 1, 0, 0, 0), Weekday::Thursday),
+        (-1, date_time(1969, 12, 31, 23, 59, 59), Weekday::Wednesday),
+        (
+            951_782_400,
+            date_time(2000, 2, 29, 0, 0, 0),
+            Weekday::Tuesday,
+        ),
+        (
+            -2_203_891_200,
+            date_time(1900, 3, 1, 0, 0, 0),
+            Weekday::Thursday,
+        ),
+        (
+            1_763_621_220,
+            date_time(2025, 11, 20, 6, 47, 0),
+            Weekday::Thursday,
+        ),
+        (
+            4_102_444_799,
+            date_time(2099, 12, 31, 23, 59, 59),
+            Weekday::Thursday,
+        ),
+    ];
+
+    for (secs, expected, weekday) in cases {
+        let timestamp = Timestamp::from_secs(secs);
+        let date_time = DateTime::from_timestamp(timestamp);
+        assert_eq!(date_time, expected);
+        assert_eq!(date_time.weekday(), weekday);
+        assert_eq!(date_time.to_timestamp(), timestamp);
+    }
+}
+
+#[test]
+fn round_trip_from_1900_to_2100() {
+    let mut secs = -2_208_988_800;
+    let mut previous = DateTime::from_timestamp(Timestamp::from_secs(secs));
+    while secs < 4_133_894_400 {
+        // Step by just under a day so every time of day and date gets visited
+        secs += 86_399;
+        let date_time = DateTime::from_timestamp(Timestamp::from_secs(secs));
+        assert_eq!(date_time.to_timestamp().secs(), secs);
+
+        assert!(date_time > previous);
+        assert!(date_time.month >= 1 && date_time.month <= 12);
+        assert!(
+            date_time.day >= 1 && date_time.day <= days_in_month(date_time.year, date_time.month)
+        );
+        assert!(date_time.hour < 24 && date_time.minute < 60 && date_time.second < 60);
+
+        previous = date_time;
+    }
+}
+
+#[test]
+fn day_of_year() {
+    assert_eq!(date_time(2025, 1, 1, 0, 0, 0).day_of_year(), 1);
+    assert_eq!(date_time(2025, 3, 1, 0, 0, 0).day_of_year(), 60);
+    assert_eq!(date_time(2024, 3, 1, 0, 0, 0).day_of_year(), 61);
+    assert_eq!(date_time(2024, 12, 31, 0, 0, 0).day_of_year(), 366);
+}
+
+#[test]
+fn iso_week() {
+    assert_eq!(date_time(2021, 1, 3, 0, 0, 0).iso_week(), (2020, 53));
+    assert_eq!(date_time(2021, 1, 4, 0, 0, 0).iso_week(), (2021, 1));
+    assert_eq!(date_time(2020, 12, 31, 0, 0, 0).iso_week(), (2020, 53));
+    assert_eq!(date_time(2008, 12, 29, 0, 0, 0).iso_week(), (2009, 1));
+    assert_eq!(date_time(2024, 12, 30, 0, 0, 0).iso_week(), (2025, 1));
+    assert_eq!(date_time(2025, 11, 20, 0, 0, 0).iso_week(), (2025, 47));
+}
+
+#[test]
+fn leap_years() {
+    assert!(is_leap_year(2000));
+    assert!(is_leap_year(2024));
+    assert!(!is_leap_year(1900));
+    assert!(!is_leap_year(2100));
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(2100, 2), 28);
+}
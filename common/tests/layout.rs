@@ -0,0 +1,134 @@
+use core::cell::Cell;
+
+use common::widgets::{
+    Widget,
+    layout::{Align, Padding, Stack},
+};
+use embedded_graphics::{
+    mock_display::MockDisplay, pixelcolor::BinaryColor, prelude::*, primitives::Rectangle,
+};
+
+/// A widget that wants to be a given size, and remembers where it was drawn.
+struct Probe {
+    size: Size,
+    area: Cell<Option<Rectangle>>,
+}
+
+impl Probe {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            size: Size::new(width, height),
+            area: Cell::new(None),
+        }
+    }
+
+    fn area(&self) -> Rectangle {
+        self.area.get().expect("not drawn")
+    }
+}
+
+impl Widget for &Probe {
+    type Color = BinaryColor;
+
+    fn size(&self, available: Size) -> Size {
+        self.size.component_min(available)
+    }
+
+    fn draw<D: DrawTarget<Color = Self::Color>>(
+        &self,
+        _target: &mut D,
+        area: Rectangle,
+    ) -> Result<(), D::Error> {
+        self.area.set(Some(area));
+        Ok(())
+    }
+}
+
+fn draw(widget: impl Widget<Color = BinaryColor>, area: Rectangle) {
+    let mut display = MockDisplay::new();
+    widget.draw(&mut display, area).unwrap();
+}
+
+fn rectangle(x: i32, y: i32, width: u32, height: u32) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+#[test]
+fn vertical_stack() {
+    let (header, plot, footer) = (Probe::new(40, 10), Probe::new(64, 64), Probe::new(20, 8));
+    draw(
+        Stack::vertical((&header, (&plot).flex(1), (&footer).fixed(12))).gap(2),
+        rectangle(0, 0, 64, 64),
+    );
+    // The flexible plot gets what's left of the height, less the gaps
+    assert_eq!(header.area(), rectangle(12, 0, 40, 10));
+    assert_eq!(plot.area(), rectangle(0, 12, 64, 38));
+    assert_eq!(footer.area(), rectangle(22, 52, 20, 12));
+}
+
+#[test]
+fn flexible_children_share_by_weight() {
+    let (left, right) = (Probe::new(0, 0), Probe::new(0, 0));
+    draw(
+        Stack::horizontal(((&left).flex(1), (&right).flex(2))).align(Align::Stretch),
+        rectangle(10, 20, 31, 8),
+    );
+    assert_eq!(left.area(), rectangle(10, 20, 10, 8));
+    assert_eq!(right.area(), rectangle(20, 20, 21, 8));
+}
+
+#[test]
+fn zero_weight_children_are_skipped() {
+    let (fixed, none, also_none) = (Probe::new(0, 0), Probe::new(0, 0), Probe::new(0, 0));
+    draw(
+        Stack::vertical(((&fixed).fixed(10), (&none).flex(0), (&also_none).flex(0)))
+            .gap(2)
+            .align(Align::Stretch)
+            .justify(Align::End),
+        rectangle(0, 0, 8, 40),
+    );
+    // With no weight to share the spare space by, the stack is justified as
+    // if the flexible children weren't there
+    assert_eq!(fixed.area(), rectangle(0, 30, 8, 10));
+    assert_eq!(none.area.get(), None);
+    assert_eq!(also_none.area.get(), None);
+}
+
+#[test]
+fn justified_stack_skips_empty_children() {
+    let (first, empty, last) = (Probe::new(8, 10), Probe::new(0, 0), Probe::new(8, 10));
+    draw(
+        Stack::vertical((&first, &empty, &last))
+            .gap(4)
+            .align(Align::Start)
+            .justify(Align::Center),
+        rectangle(0, 0, 8, 40),
+    );
+    // 24 pixels of content, with a single gap, centered in 40
+    assert_eq!(first.area(), rectangle(0, 8, 8, 10));
+    assert_eq!(empty.area.get(), None);
+    assert_eq!(last.area(), rectangle(0, 22, 8, 10));
+}
+
+#[test]
+fn padding_and_alignment() {
+    let probe = Probe::new(10, 10);
+    draw(
+        (&probe).padded(Padding::symmetric(4, 2)),
+        rectangle(0, 0, 30, 30),
+    );
+    assert_eq!(probe.area(), rectangle(4, 2, 22, 26));
+
+    let probe = Probe::new(10, 10);
+    assert_eq!(
+        (&probe).padded(Padding::all(3)).size(Size::new(30, 30)),
+        Size::new(16, 16)
+    );
+
+    let probe = Probe::new(10, 10);
+    draw(
+        (&probe).aligned(Align::End, Align::Center),
+        rectangle(0, 0, 30, 30),
+    );
+    assert_eq!(probe.area(), rectangle(20, 10, 10, 10));
+}
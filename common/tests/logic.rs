@@ -0,0 +1,32 @@
+use common::{
+    calendar::moon::{PhaseEvent, PrincipalPhase},
+    logic,
+    rtclock::Timestamp,
+    timezone::TimeZone,
+};
+
+#[test]
+fn next_phase_countdown_and_local_time() {
+    // Nov 5, 2025 13:19 UTC
+    let event = PhaseEvent {
+        phase: PrincipalPhase::FirstQuarter,
+        instant: Timestamp::from_secs(1_762_348_740),
+    };
+    let tz = TimeZone::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    let mut buf = [0u8; 48];
+
+    // Nov 2, 2025 09:07 UTC
+    let now = Timestamp::from_secs(1_762_348_740 - (3 * 86_400 + 4 * 3600 + 12 * 60));
+    assert_eq!(
+        logic::format_next_phase(&mut buf, now, &event, &tz),
+        "first quarter\nin 3d 4h\n(Nov 05 14:19)"
+    );
+
+    // Less than a day away, and after midnight in local time
+    let now = Timestamp::from_secs(1_762_348_740 - (4 * 3600 + 12 * 60));
+    let tz = TimeZone::parse("NZST-12NZDT,M9.5.0,M4.1.0/3").unwrap();
+    assert_eq!(
+        logic::format_next_phase(&mut buf, now, &event, &tz),
+        "first quarter\nin 4h 12m\n(Nov 06 02:19)"
+    );
+}
@@ -0,0 +1,71 @@
+use common::{
+    calendar::Observer,
+    pages::{DayNight, Fixed, Page, Rotate, Schedule},
+    rtclock::InstantSecs,
+};
+
+const GREENWICH: Observer = Observer {
+    latitude: 51.4769,
+    longitude: -0.0005,
+    elevation: 0.0,
+};
+
+/// Oct 18, 2025 00:00 UTC
+const MIDNIGHT: u64 = 1_760_745_600;
+
+#[test]
+fn page_names() {
+    for page in Page::ALL {
+        assert_eq!(Page::from_name(page.name()), Some(page));
+    }
+    assert_eq!(Page::from_name("time-standards"), Some(Page::TimeStandards));
+    assert_eq!(Page::from_name("moon"), Some(Page::Moon));
+    assert_eq!(Page::from_name("planets"), Some(Page::Planets));
+    assert_eq!(Page::from_name("stars"), None);
+}
+
+#[test]
+fn fixed_and_custom_schedules() {
+    let instant = InstantSecs::from_ticks(MIDNIGHT);
+    assert_eq!(Fixed(Page::Month).page_at(instant), Page::Month);
+
+    let weekends = |instant: InstantSecs| {
+        // Jan 1, 1970 was a Thursday
+        if (instant.ticks() / 86_400 + 3) % 7 >= 5 {
+            Page::Daylight
+        } else {
+            Page::Main
+        }
+    };
+    assert_eq!(weekends.page_at(instant), Page::Daylight);
+}
+
+#[test]
+fn rotate() {
+    let schedule = Rotate {
+        pages: &[Page::Main, Page::Sun, Page::Month],
+        period_secs: 3600,
+    };
+    let page_at = |secs| schedule.page_at(InstantSecs::from_ticks(secs));
+    // Midnight is a multiple of three hours from the epoch
+    assert_eq!(page_at(MIDNIGHT), Page::Main);
+    assert_eq!(page_at(MIDNIGHT + 3599), Page::Main);
+    assert_eq!(page_at(MIDNIGHT + 3600), Page::Sun);
+    assert_eq!(page_at(MIDNIGHT + 2 * 3600), Page::Month);
+    assert_eq!(page_at(MIDNIGHT + 3 * 3600), Page::Main);
+}
+
+#[test]
+fn day_and_night() {
+    let schedule = DayNight {
+        day: Page::Sun,
+        night: Page::Moon,
+        observer: GREENWICH,
+    };
+    let page_at = |secs| schedule.page_at(InstantSecs::from_ticks(secs));
+    assert_eq!(page_at(MIDNIGHT), Page::Moon);
+    assert_eq!(page_at(MIDNIGHT + 12 * 3600), Page::Sun);
+    // Sunrise was at 06:27 UTC
+    assert_eq!(page_at(MIDNIGHT + 6 * 3600 + 20 * 60), Page::Moon);
+    assert_eq!(page_at(MIDNIGHT + 6 * 3600 + 35 * 60), Page::Sun);
+}
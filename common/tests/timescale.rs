@@ -0,0 +1,109 @@
+use common::{calendar::Observer, rtclock::Timestamp, timescale};
+
+/// Checks that two angles in degrees are within `tolerance_secs` seconds of
+/// time of each other.
+fn assert_sidereal_time(actual: f64, expected_hms: (f64, f64, f64), tolerance_secs: f64) {
+    let (hours, minutes, secs) = expected_hms;
+    let expected = (hours + minutes / 60.0 + secs / 3600.0) * 15.0;
+    let difference_secs = (actual - expected) / 15.0 * 3600.0;
+    assert!(
+        difference_secs.abs() <= tolerance_secs,
+        "{actual} is not within {tolerance_secs}s of {expected}"
+    );
+}
+
+#[test]
+fn julian_dates() {
+    // J2000.0, Jan 1, 2000 12:00 UTC
+    let j2000 = Timestamp::from_secs(946_728_000);
+    assert_eq!(timescale::julian_date(j2000), 2_451_545.0);
+    assert_eq!(timescale::modified_julian_date(j2000), 51_544.5);
+    assert_eq!(timescale::timestamp_from_julian_date(2_451_545.0), j2000);
+
+    // The unix epoch
+    assert_eq!(timescale::julian_date(Timestamp::from_secs(0)), 2_440_587.5);
+    assert_eq!(
+        timescale::modified_julian_date(Timestamp::from_secs(0)),
+        40_587.0
+    );
+}
+
+#[test]
+fn leap_seconds() {
+    // Before UTC had leap seconds
+    assert_eq!(
+        timescale::tai_minus_utc(Timestamp::from_secs(63_071_999)),
+        None
+    );
+    // Jan 1, 1972
+    assert_eq!(
+        timescale::tai_minus_utc(Timestamp::from_secs(63_072_000)),
+        Some(10)
+    );
+    // Either side of the leap second at the end of 2016
+    assert_eq!(
+        timescale::tai_minus_utc(Timestamp::from_secs(1_483_228_799)),
+        Some(36)
+    );
+    assert_eq!(
+        timescale::tai_minus_utc(Timestamp::from_secs(1_483_228_800)),
+        Some(37)
+    );
+    // Oct 18, 2025
+    let instant = Timestamp::from_secs(1_760_745_600);
+    assert_eq!(timescale::tai_minus_utc(instant), Some(37));
+    assert_eq!(timescale::tt_minus_utc(instant), 69.184);
+}
+
+#[test]
+fn delta_t() {
+    // Jan 1, 1900, when ΔT was about -2.7s
+    let instant = Timestamp::from_secs(-2_208_988_800);
+    assert!((timescale::delta_t(instant) - -2.7).abs() < 0.5);
+    // Before leap seconds, TT - UTC comes from the model
+    assert_eq!(
+        timescale::tt_minus_utc(instant),
+        timescale::delta_t(instant)
+    );
+
+    // Jan 1, 2000, when ΔT was 63.8s
+    let instant = Timestamp::from_secs(946_684_800);
+    assert!((timescale::delta_t(instant) - 63.8).abs() < 0.5);
+    assert!((timescale::julian_ephemeris_date(instant) - 2_451_544.500_743).abs() < 1e-6);
+}
+
+#[test]
+fn sidereal_time() {
+    // Apr 10, 1987 00:00 UTC (Meeus example 12.a)
+    let instant = Timestamp::from_secs(545_011_200);
+    assert_sidereal_time(
+        timescale::greenwich_mean_sidereal_time(instant),
+        (13.0, 10.0, 46.3668),
+        0.001,
+    );
+    assert_sidereal_time(
+        timescale::greenwich_apparent_sidereal_time(instant),
+        (13.0, 10.0, 46.1351),
+        0.05,
+    );
+
+    // Apr 10, 1987 19:21 UTC (Meeus example 12.b)
+    assert_sidereal_time(
+        timescale::greenwich_mean_sidereal_time(instant + 69_660),
+        (8.0, 34.0, 57.0896),
+        0.001,
+    );
+
+    // Six hours of longitude east of Greenwich, sidereal time is six hours
+    // later
+    let observer = Observer {
+        latitude: 0.0,
+        longitude: 90.0,
+        elevation: 0.0,
+    };
+    assert_sidereal_time(
+        timescale::local_sidereal_time(instant, &observer),
+        (19.0, 10.0, 46.1351),
+        0.05,
+    );
+}
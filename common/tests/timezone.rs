@@ -0,0 +1,130 @@
+use common::{
+    datetime::DateTime,
+    rtclock::Timestamp,
+    timezone::{ParseError, TimeZone},
+};
+
+/// Checks the offset either side of a transition at `transition` (UTC).
+fn assert_transition(tz: &TimeZone, transition: i64, before: (i32, &str), after: (i32, &str)) {
+    let offset = tz.offset_at(Timestamp::from_secs(transition - 1));
+    assert_eq!((offset.secs, offset.name), before);
+    let offset = tz.offset_at(Timestamp::from_secs(transition));
+    assert_eq!((offset.secs, offset.name), after);
+}
+
+#[test]
+fn central_european_time() {
+    let tz = TimeZone::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+    // Mar 30, 2025 01:00 UTC
+    assert_transition(&tz, 1_743_296_400, (3600, "CET"), (7200, "CEST"));
+    // Oct 26, 2025 01:00 UTC
+    assert_transition(&tz, 1_761_440_400, (7200, "CEST"), (3600, "CET"));
+}
+
+#[test]
+fn us_eastern_time() {
+    let tz = TimeZone::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+    // Mar 9, 2025 07:00 UTC
+    assert_transition(&tz, 1_741_503_600, (-18_000, "EST"), (-14_400, "EDT"));
+    // Nov 2, 2025 06:00 UTC
+    assert_transition(&tz, 1_762_063_200, (-14_400, "EDT"), (-18_000, "EST"));
+
+    // The same rules are assumed when they're left out
+    assert_eq!(TimeZone::parse("EST5EDT").unwrap(), tz);
+}
+
+#[test]
+fn southern_hemisphere() {
+    let tz = TimeZone::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+    // Apr 5, 2025 16:00 UTC
+    assert_transition(&tz, 1_743_868_800, (39_600, "AEDT"), (36_000, "AEST"));
+    // Oct 4, 2025 16:00 UTC
+    assert_transition(&tz, 1_759_593_600, (36_000, "AEST"), (39_600, "AEDT"));
+}
+
+#[test]
+fn fixed_offsets() {
+    let tz = TimeZone::parse("<+0530>-5:30").unwrap();
+    let offset = tz.offset_at(Timestamp::from_secs(1_751_371_200));
+    assert_eq!((offset.secs, offset.name), (19_800, "+0530"));
+    assert!(!offset.is_daylight_saving);
+
+    let tz = TimeZone::parse("UTC0").unwrap();
+    assert_eq!(tz.offset_at(Timestamp::from_secs(0)).secs, 0);
+}
+
+#[test]
+fn julian_day_rules() {
+    // Daylight saving all year, from Jan 1 00:00 to Dec 31 25:00 standard
+    // time, as zic writes for permanent daylight saving zones
+    let tz = TimeZone::parse("EST5EDT,0/0,J365/25").unwrap();
+    assert!(
+        tz.offset_at(Timestamp::from_secs(1_751_371_200))
+            .is_daylight_saving
+    );
+    assert!(
+        tz.offset_at(Timestamp::from_secs(1_735_689_600))
+            .is_daylight_saving
+    );
+}
+
+#[test]
+fn local_time_round_trip() {
+    let tz = TimeZone::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+
+    // Jul 1, 2025 12:00 UTC is 14:00 CEST
+    let timestamp = Timestamp::from_secs(1_751_371_200);
+    let local = tz.to_local(timestamp);
+    assert_eq!((local.day, local.hour), (1, 14));
+    assert_eq!(tz.from_local(&local), timestamp);
+
+    // 02:30 on Mar 30 doesn't exist, so is taken as standard time (03:30 CEST)
+    let skipped = DateTime {
+        year: 2025,
+        month: 3,
+        day: 30,
+        hour: 2,
+        minute: 30,
+        second: 0,
+    };
+    assert_eq!(tz.from_local(&skipped), Timestamp::from_secs(1_743_298_200));
+
+    // 02:30 on Oct 26 happens twice, and the first (CEST) is used
+    let repeated = DateTime {
+        month: 10,
+        day: 26,
+        ..skipped
+    };
+    assert_eq!(
+        tz.from_local(&repeated),
+        Timestamp::from_secs(1_761_438_600)
+    );
+}
+
+#[test]
+fn invalid_strings() {
+    assert_eq!(TimeZone::parse(""), Err(ParseError::InvalidName));
+    assert_eq!(TimeZone::parse("CET"), Err(ParseError::InvalidOffset));
+    assert_eq!(TimeZone::parse("CET-25"), Err(ParseError::InvalidOffset));
+    // Hours large enough to overflow when converted to seconds
+    assert_eq!(
+        TimeZone::parse("UTC5000000"),
+        Err(ParseError::InvalidOffset)
+    );
+    assert_eq!(
+        TimeZone::parse("UTC99999999999"),
+        Err(ParseError::InvalidOffset)
+    );
+    assert_eq!(
+        TimeZone::parse("CET-1CEST,M3.5.0/5000000,M10.5.0/3"),
+        Err(ParseError::InvalidOffset)
+    );
+    assert_eq!(
+        TimeZone::parse("CET-1CEST,M13.5.0,M10.5.0/3"),
+        Err(ParseError::InvalidRule)
+    );
+    assert_eq!(
+        TimeZone::parse("CET-1CEST,M3.5.0,M10.5.0/3 "),
+        Err(ParseError::TrailingCharacters)
+    );
+}
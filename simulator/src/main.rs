@@ -1,50 +1,136 @@
+mod options;
+mod panel;
+
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use common::{
-    logic::draw_frame,
+    calendar::Observer,
+    pages::{DayNight, Page, Schedule},
     rtclock::{InstantSecs, RealTimeClock},
-    theme::Theme,
+    theme::{BinaryTheme, JD79661Theme},
+    timezone::TimeZone,
+};
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+use embedded_graphics_simulator::{
+    BinaryColorTheme, OutputSettings, OutputSettingsBuilder, SimulatorDisplay,
 };
-use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
-use embedded_graphics_simulator::{OutputSettingsBuilder, SimulatorDisplay, Window};
 
-struct SimulatorTheme;
+use crate::{
+    options::{Options, Palette},
+    panel::{Inks, PanelPreview},
+};
 
-impl Theme for SimulatorTheme {
-    type Color = BinaryColor;
+/// Royal Observatory, Greenwich
+const OBSERVER: Observer = Observer {
+    latitude: 51.4769,
+    longitude: -0.0005,
+    elevation: 46.0,
+};
 
-    fn background(&self) -> Self::Color {
-        BinaryColor::Off
-    }
+/// UK time, as a POSIX TZ string
+const TIME_ZONE: &str = "GMT0BST,M3.5.0/1,M10.5.0";
 
-    fn text(&self) -> Self::Color {
-        BinaryColor::On
-    }
-}
+/// The sun page by day, and the moon page by night. As on the sundial.
+const SCHEDULE: DayNight = DayNight {
+    day: Page::Sun,
+    night: Page::Moon,
+    observer: OBSERVER,
+};
 
-struct SimulatorClock;
+/// Tells the time from the system clock, or keeps to a given time.
+struct SimulatorClock {
+    time: Option<InstantSecs>,
+}
 
 impl RealTimeClock for SimulatorClock {
     fn get_time(&self) -> InstantSecs {
-        let ticks = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-        InstantSecs::from_ticks(ticks)
+        self.time.unwrap_or_else(|| {
+            let ticks = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            InstantSecs::from_ticks(ticks)
+        })
     }
 }
 
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!("{}", Options::usage());
+    std::process::exit(2);
+}
+
 fn main() -> Result<(), core::convert::Infallible> {
-    let mut display = SimulatorDisplay::<BinaryColor>::new(Size::new(128, 250));
-    let theme = SimulatorTheme {};
-    let clock = SimulatorClock {};
+    let options = Options::parse(std::env::args().skip(1))
+        .unwrap_or_else(|message| exit_with_usage(&message));
+    let clock = SimulatorClock { time: options.time };
+    let observer = options.observer.unwrap_or(OBSERVER);
+    let time_zone = options.time_zone.as_deref().unwrap_or(TIME_ZONE);
+    let time_zone = TimeZone::parse(time_zone).unwrap_or_else(|error| {
+        exit_with_usage(&format!("Invalid time zone \"{time_zone}\": {error:?}"))
+    });
+    let page = options.page.unwrap_or_else(|| {
+        let schedule = DayNight {
+            observer,
+            ..SCHEDULE
+        };
+        schedule.page_at(clock.get_time())
+    });
+    let palette = options.palette.unwrap_or(Palette::Panel(Inks::Pure));
 
-    draw_frame(&mut display, &theme, &clock)?;
+    let mut display = SimulatorDisplay::<Rgb888>::new(Size::new(128, 250));
+    match palette {
+        Palette::Binary => page.draw(
+            &mut display.color_converted(),
+            &BinaryTheme,
+            &clock,
+            &observer,
+            &time_zone,
+        )?,
+        Palette::Panel(inks) => page.draw(
+            &mut PanelPreview::new(&mut display, inks),
+            &JD79661Theme::new(),
+            &clock,
+            &observer,
+            &time_zone,
+        )?,
+    }
 
-    let output_settings = OutputSettingsBuilder::new()
-        .theme(embedded_graphics_simulator::BinaryColorTheme::OledWhite)
-        .build();
-    Window::new("Sundial", &output_settings).show_static(&display);
+    // Files get one pixel per pixel of the panel. The window magnifies them,
+    // with gaps between, so they're easier to make out
+    let (scale, pixel_spacing) = match options.output {
+        Some(_) => (1, 0),
+        None => (3, 1),
+    };
+    let mut output_settings = OutputSettingsBuilder::new()
+        .scale(scale)
+        .pixel_spacing(pixel_spacing);
+    // The binary palette is drawn in pure black and white, and this softens
+    // the black. The panel's colors are shown as they are
+    if palette == Palette::Binary {
+        output_settings = output_settings.theme(BinaryColorTheme::OledWhite);
+    }
+    let output_settings = output_settings.build();
+    match options.output {
+        Some(path) => {
+            let image = display.to_rgb_output_image(&output_settings);
+            if let Err(error) = image.save_png(&path) {
+                eprintln!("Couldn't write {}: {error}", path.display());
+                std::process::exit(1);
+            }
+        }
+        None => show_window(&display, &output_settings),
+    }
 
     Ok(())
 }
+
+#[cfg(feature = "window")]
+fn show_window(display: &SimulatorDisplay<Rgb888>, output_settings: &OutputSettings) {
+    embedded_graphics_simulator::Window::new("Sundial", output_settings).show_static(display);
+}
+
+#[cfg(not(feature = "window"))]
+fn show_window(_display: &SimulatorDisplay<Rgb888>, _output_settings: &OutputSettings) {
+    exit_with_usage("Built without the window feature, so pass --output to write a PNG");
+}
@@ -0,0 +1,267 @@
+use std::{path::PathBuf, str::FromStr};
+
+use common::{
+    calendar::Observer,
+    datetime::{DateTime, days_in_month},
+    pages::Page,
+    rtclock::{InstantSecs, Timestamp},
+};
+
+use crate::panel::Inks;
+
+/// The colors to draw the page in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Palette {
+    /// Black and white, as on a two color display.
+    Binary,
+    /// The sundial's four color panel.
+    Panel(Inks),
+}
+
+impl Palette {
+    const NAMES: [(&str, Palette); 3] = [
+        ("panel", Palette::Panel(Inks::Pure)),
+        ("muted", Palette::Panel(Inks::Muted)),
+        ("binary", Palette::Binary),
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .into_iter()
+            .find(|(palette, _)| *palette == name)
+            .map(|(_, palette)| palette)
+    }
+}
+
+/// What to draw, and where, from the command line. Anything left out falls
+/// back on the simulator's defaults.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// The page to show, instead of the one the schedule picks.
+    pub page: Option<Page>,
+    pub palette: Option<Palette>,
+    /// The time to draw, instead of now.
+    pub time: Option<InstantSecs>,
+    pub observer: Option<Observer>,
+    /// POSIX TZ string to show local times in.
+    pub time_zone: Option<String>,
+    /// PNG file to write the page to, instead of showing it in a window.
+    pub output: Option<PathBuf>,
+}
+
+impl Options {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {arg}"))
+            };
+            match arg.as_str() {
+                "--palette" => {
+                    let name = value()?;
+                    let palette = Palette::from_name(&name)
+                        .ok_or_else(|| format!("Unknown palette \"{name}\""))?;
+                    options.palette = Some(palette);
+                }
+                "--time" => {
+                    let time = value()?;
+                    let instant = parse_time(&time)
+                        .ok_or_else(|| format!("Invalid time \"{time}\""))?
+                        .to_instant()
+                        .ok_or_else(|| format!("Time \"{time}\" is before 1970"))?;
+                    options.time = Some(instant);
+                }
+                "--location" => {
+                    let location = value()?;
+                    let observer = parse_location(&location)
+                        .ok_or_else(|| format!("Invalid location \"{location}\""))?;
+                    options.observer = Some(observer);
+                }
+                "--time-zone" => options.time_zone = Some(value()?),
+                "--output" => options.output = Some(value()?.into()),
+                name => {
+                    let page =
+                        Page::from_name(name).ok_or_else(|| format!("Unknown page \"{name}\""))?;
+                    options.page = Some(page);
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    pub fn usage() -> String {
+        let pages: Vec<_> = Page::ALL.iter().map(Page::name).collect();
+        let palettes: Vec<_> = Palette::NAMES.iter().map(|(name, _)| *name).collect();
+        format!(
+            "Usage: simulator [--palette {}] [--time TIME] [--location LAT,LON[,ELEVATION]]\n\
+             \x20                [--time-zone TZ] [--output FILE.png] [{}]",
+            palettes.join("|"),
+            pages.join("|")
+        )
+    }
+}
+
+/// Parses a time given as unix seconds, or in RFC 3339 format, such as
+/// "2026-08-12T17:46:00Z" or "2026-08-12 19:46:00+02:00". Fractions of a
+/// second are ignored.
+fn parse_time(time: &str) -> Option<Timestamp> {
+    if let Ok(secs) = time.parse() {
+        return Some(Timestamp::from_secs(secs));
+    }
+
+    let date_time = DateTime {
+        year: number(time.get(0..4)?)?,
+        month: number(time.get(5..7)?)?,
+        day: number(time.get(8..10)?)?,
+        hour: number(time.get(11..13)?)?,
+        minute: number(time.get(14..16)?)?,
+        second: number(time.get(17..19)?)?,
+    };
+    let separators = [(4, b'-'), (7, b'-'), (13, b':'), (16, b':')];
+    if separators.iter().any(|&(i, c)| time.as_bytes()[i] != c)
+        || !matches!(time.as_bytes()[10], b'T' | b't' | b' ')
+        || !(1..=12).contains(&date_time.month)
+        || !(1..=days_in_month(date_time.year, date_time.month)).contains(&date_time.day)
+        || date_time.hour > 23
+        || date_time.minute > 59
+        || date_time.second > 60
+    {
+        return None;
+    }
+
+    let mut rest = &time[19..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        rest = fraction.trim_start_matches(|c: char| c.is_ascii_digit());
+        if rest.len() == fraction.len() {
+            return None;
+        }
+    }
+    let offset_secs: i64 = match rest.as_bytes() {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let hours: i64 = number(&rest[1..3])?;
+            let minutes: i64 = number(&rest[4..6])?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let secs = hours * 3600 + minutes * 60;
+            if *sign == b'-' { -secs } else { secs }
+        }
+        _ => return None,
+    };
+    Some(date_time.to_timestamp() - offset_secs)
+}
+
+/// Parses a field of digits, without the sign `str::parse` would accept.
+fn number<T: FromStr>(digits: &str) -> Option<T> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Parses a location given as "latitude,longitude" in degrees north and east,
+/// optionally followed by ",elevation" in meters.
+fn parse_location(location: &str) -> Option<Observer> {
+    let mut parts = location.split(',').map(|part| part.trim().parse::<f64>());
+    let latitude = parts.next()?.ok()?;
+    let longitude = parts.next()?.ok()?;
+    let elevation = parts.next().transpose().ok()?.unwrap_or(0.0);
+    if parts.next().is_some()
+        || !(-90.0..=90.0).contains(&latitude)
+        || !(-180.0..=180.0).contains(&longitude)
+    {
+        return None;
+    }
+    Some(Observer {
+        latitude,
+        longitude,
+        elevation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Aug 12, 2026 17:46 UTC, during the total solar eclipse
+    const ECLIPSE: i64 = 1_786_556_760;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter().map(|arg| arg.to_string())
+    }
+
+    #[test]
+    fn rfc_3339_and_unix_times() {
+        let eclipse = Some(Timestamp::from_secs(ECLIPSE));
+        assert_eq!(parse_time("2026-08-12T17:46:00Z"), eclipse);
+        assert_eq!(parse_time("2026-08-12 19:46:00+02:00"), eclipse);
+        assert_eq!(parse_time("2026-08-12t12:46:00-05:00"), eclipse);
+        assert_eq!(parse_time("2026-08-12T17:46:00.750z"), eclipse);
+        assert_eq!(parse_time("1786556760"), eclipse);
+
+        // A leap second is the same as the first second of the next minute
+        assert_eq!(
+            parse_time("2016-12-31T23:59:60Z"),
+            Some(Timestamp::from_secs(1_483_228_800))
+        );
+        assert_eq!(
+            parse_time("1969-12-31T23:59:59Z"),
+            Some(Timestamp::from_secs(-1))
+        );
+    }
+
+    #[test]
+    fn malformed_times() {
+        for time in [
+            "",
+            "2026-13-01T00:00:00Z",
+            "2026-02-29T00:00:00Z",
+            "2026-08-12T24:00:00Z",
+            "2026-08-12T17:60:00Z",
+            "2026-08-12T17:46:61Z",
+            "2026-08-12T17:46:00",
+            "2026-08-12T17:46:00.Z",
+            "2026-08-12T17:46:00+0200",
+            "2026-08-12T17:46:00+24:00",
+            "2026-08-12T17:46:00Zjunk",
+            "2026/08/12T17:46:00Z",
+            "2026-08-12_17:46:00Z",
+            "2026-+8-12T17:46:00Z",
+            "17:46",
+        ] {
+            assert_eq!(parse_time(time), None, "{time:?}");
+        }
+    }
+
+    #[test]
+    fn times_before_1970_are_rejected() {
+        for time in ["1969-12-31T23:59:59Z", "-1"] {
+            let error = Options::parse(args(&["--time", time])).unwrap_err();
+            assert!(error.contains("before 1970"), "{error}");
+        }
+        let options = Options::parse(args(&["--time", "1970-01-01T00:00:00Z"])).unwrap();
+        assert_eq!(options.time, Some(InstantSecs::from_ticks(0)));
+    }
+
+    #[test]
+    fn locations() {
+        let observer = parse_location("78.22, 15.65, 10").unwrap();
+        assert_eq!(
+            (observer.latitude, observer.longitude, observer.elevation),
+            (78.22, 15.65, 10.0)
+        );
+        let observer = parse_location("-90,180").unwrap();
+        assert_eq!(
+            (observer.latitude, observer.longitude, observer.elevation),
+            (-90.0, 180.0, 0.0)
+        );
+
+        for location in [
+            "", "51.5", "91,0", "0,-181", "51.5,x", "51.5,0,x", "1,2,3,4",
+        ] {
+            assert!(parse_location(location).is_none(), "{location:?}");
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use common::theme::JD79661Color;
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*, primitives::Rectangle};
+
+/// How to show the JD79661's inks on a screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Inks {
+    /// Pure black, white, yellow and red, to tell the colors apart at a glance.
+    Pure,
+    /// Roughly the colors the panel really shows: off-black, the grey-white of
+    /// the paper, and a dull mustard and brick red.
+    Muted,
+}
+
+impl Inks {
+    pub fn rgb(self, color: JD79661Color) -> Rgb888 {
+        match (self, color) {
+            (Inks::Pure, JD79661Color::Black) => Rgb888::BLACK,
+            (Inks::Pure, JD79661Color::White) => Rgb888::WHITE,
+            (Inks::Pure, JD79661Color::Yellow) => Rgb888::YELLOW,
+            (Inks::Pure, JD79661Color::Red) => Rgb888::RED,
+            (Inks::Muted, JD79661Color::Black) => Rgb888::new(0x2b, 0x2a, 0x2f),
+            (Inks::Muted, JD79661Color::White) => Rgb888::new(0xd8, 0xd6, 0xce),
+            (Inks::Muted, JD79661Color::Yellow) => Rgb888::new(0xd6, 0xb2, 0x2a),
+            (Inks::Muted, JD79661Color::Red) => Rgb888::new(0xa3, 0x3a, 0x32),
+        }
+    }
+}
+
+/// Draws in the JD79661's colors onto a full color target, such as the
+/// simulator's display, so pages can be previewed as they'll look on the
+/// panel.
+pub struct PanelPreview<'a, D> {
+    target: &'a mut D,
+    inks: Inks,
+}
+
+impl<'a, D> PanelPreview<'a, D> {
+    pub fn new(target: &'a mut D, inks: Inks) -> Self {
+        Self { target, inks }
+    }
+}
+
+impl<D: Dimensions> Dimensions for PanelPreview<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D: DrawTarget<Color = Rgb888>> DrawTarget for PanelPreview<'_, D> {
+    type Color = JD79661Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let inks = self.inks;
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(point, inks.rgb(color))),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.fill_solid(area, self.inks.rgb(color))
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.clear(self.inks.rgb(color))
+    }
+}
@@ -1,40 +0,0 @@
-#!/usr/bin/env bash
-
-dates=(
-    2025-10-22
-    2025-10-23
-    2025-10-24
-    2025-10-25
-    2025-10-26
-    2025-10-27
-    2025-10-28
-    2025-10-29
-    2025-10-30
-    2025-10-31
-    2025-11-01
-    2025-11-02
-    2025-11-03
-    2025-11-04
-    2025-11-05
-    2025-11-06
-    2025-11-07
-    2025-11-08
-    2025-11-09
-    2025-11-10
-    2025-11-11
-    2025-11-12
-    2025-11-13
-    2025-11-14
-    2025-11-15
-    2025-11-16
-    2025-11-17
-    2025-11-18
-    2025-11-19
-    2025-11-20
-)
-
-echo "Date,Phase"
-for date in "${dates[@]}"; do
-    phase=$(tungsten "phase of the moon at $date midnight utc" | grep "illuminated" | cut -d " " -f 1)
-    echo "$date,$phase"
-done
@@ -1,27 +1,11 @@
-use common::theme::Theme;
-use embedded_graphics::{pixelcolor::raw::RawU2, prelude::*, primitives::Rectangle};
+use common::theme::JD79661Color;
+use embedded_graphics::{prelude::*, primitives::Rectangle};
 
 use crate::jd79661::{HEIGHT, PIXDEPTH, WIDTH};
 
-/// embedded_graphics support for the JD79661
-
-#[derive(PartialEq, Clone, Copy)]
-#[repr(u8)]
-#[derive(Default)]
-pub enum JD79661Color {
-    Black = 0b00,
-    #[default]
-    White = 0b01,
-    Yellow = 0b10,
-    Red = 0b11,
-}
-
-impl PixelColor for JD79661Color {
-    type Raw = RawU2;
-}
-
 const BUFFER_LENGTH: usize = WIDTH * HEIGHT * PIXDEPTH / 8;
 
+/// embedded_graphics support for the JD79661
 pub struct JD79661Display {
     buffer: [u8; BUFFER_LENGTH],
 }
@@ -96,23 +80,3 @@ impl DrawTarget for JD79661Display {
         Ok(())
     }
 }
-
-pub struct JD79661Theme;
-
-impl JD79661Theme {
-    pub fn new() -> Self {
-        Self {}
-    }
-}
-
-impl Theme for JD79661Theme {
-    type Color = JD79661Color;
-
-    fn background(&self) -> Self::Color {
-        Self::Color::Black
-    }
-
-    fn text(&self) -> Self::Color {
-        Self::Color::White
-    }
-}
@@ -5,8 +5,11 @@ mod exclusive_spi_device;
 mod jd79661;
 mod jd79661_display;
 
-use common::logic;
-use common::rtclock;
+use common::calendar::Observer;
+use common::pages::{DayNight, Page, Schedule};
+use common::rtclock::{self, RealTimeClock};
+use common::theme::JD79661Theme;
+use common::timezone::TimeZone;
 use defmt::*;
 use defmt_rtt as _;
 use embedded_hal::delay::DelayNs;
@@ -31,7 +34,6 @@ use hal::Spi;
 use hal::fugit::RateExtU32;
 use hal::gpio::FunctionSpi;
 
-use crate::jd79661_display::JD79661Theme;
 use crate::{
     exclusive_spi_device::ExclusiveSpiDevice, jd79661::JD79661, jd79661_display::JD79661Display,
 };
@@ -59,6 +61,25 @@ pub static IMAGE_DEF: hal::block::ImageDef = hal::block::ImageDef::secure_exe();
 /// Adjust if your board has a different frequency
 const XTAL_FREQ_HZ: u32 = 12_000_000u32;
 
+/// Where the display is located, for computing rise/set times. Royal
+/// Observatory, Greenwich.
+const OBSERVER: Observer = Observer {
+    latitude: 51.4769,
+    longitude: -0.0005,
+    elevation: 46.0,
+};
+
+/// Time zone for displayed times, as a POSIX TZ string. UK time.
+const TIME_ZONE: &str = "GMT0BST,M3.5.0/1,M10.5.0";
+
+/// Which page to show at each refresh: the sun page by day, and the moon page
+/// by night.
+const SCHEDULE: DayNight = DayNight {
+    day: Page::Sun,
+    night: Page::Moon,
+    observer: OBSERVER,
+};
+
 /// Entry point to our bare-metal application.
 ///
 /// The `#[hal::entry]` macro ensures the Cortex-M start-up code calls this function
@@ -157,9 +178,11 @@ fn _main() -> Result<(), core::convert::Infallible> {
 
     let mut display = JD79661Display::default();
     let theme = JD79661Theme::new();
+    let time_zone = TimeZone::parse(TIME_ZONE).unwrap();
 
     loop {
-        logic::draw_frame(&mut display, &theme, &clock)?;
+        let page = SCHEDULE.page_at(clock.get_time());
+        page.draw(&mut display, &theme, &clock, &OBSERVER, &time_zone)?;
 
         screen.write_buffer(display.buffer())?;
         screen.update_sleep(&mut timer)?;